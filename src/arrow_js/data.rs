@@ -6,6 +6,7 @@ use wasm_bindgen::JsCast;
 
 use crate::arrow_js::r#type::{import_data_type, JSDataType};
 use crate::error::WasmResult;
+use crate::validation::ValidationLevel;
 
 #[wasm_bindgen]
 extern "C" {
@@ -248,13 +249,21 @@ fn copy_value_offsets_for_type(js_data: &JSData, data_type: &DataType) -> WasmRe
     }
 }
 
-pub fn import_data(js_data: &JSData) -> WasmResult<ArrayData> {
+/// Copy an Arrow JS `Data` and its children into WebAssembly memory, validating the result at
+/// the given level.
+pub fn import_data(js_data: &JSData, validation: ValidationLevel) -> WasmResult<ArrayData> {
+    let data = import_data_unchecked(js_data)?;
+    validation.validate(&data)?;
+    Ok(data)
+}
+
+fn import_data_unchecked(js_data: &JSData) -> WasmResult<ArrayData> {
     let mut child_data = vec![];
     for child in js_data.children() {
-        child_data.push(import_data(&child)?);
+        child_data.push(import_data_unchecked(&child)?);
     }
 
-    let data_type = import_data_type(&js_data.data_type());
+    let data_type = import_data_type(&js_data.data_type())?;
 
     // TODO: support dictionary
     let buffers = match &data_type {
@@ -277,14 +286,14 @@ pub fn import_data(js_data: &JSData) -> WasmResult<ArrayData> {
         }
     };
 
-    Ok(ArrayData::try_new(
-        data_type,
-        js_data.length(),
-        copy_null_bitmap(js_data),
-        js_data.offset(),
-        buffers,
-        child_data,
-    )?)
+    let builder = ArrayData::builder(data_type)
+        .len(js_data.length())
+        .null_bit_buffer(copy_null_bitmap(js_data))
+        .offset(js_data.offset())
+        .buffers(buffers)
+        .child_data(child_data);
+    // Safety: the caller validates the whole tree at the requested level
+    Ok(unsafe { builder.build_unchecked() })
 }
 
 #[cfg(all(test, target_arch = "wasm32"))]
@@ -349,7 +358,7 @@ mod tests {
             values.into(),
             js_sys::Int32Array::new_with_length(0).into(),
         );
-        let imported = import_data(data.unchecked_ref()).unwrap();
+        let imported = import_data(data.unchecked_ref(), ValidationLevel::Full).unwrap();
         let array = make_array(imported);
         let actual = array
             .as_primitive::<arrow_array::types::Int32Type>()
@@ -367,7 +376,7 @@ mod tests {
             values.into(),
             js_sys::Int32Array::new_with_length(0).into(),
         );
-        let imported = import_data(data.unchecked_ref()).unwrap();
+        let imported = import_data(data.unchecked_ref(), ValidationLevel::Full).unwrap();
         let array = make_array(imported);
         let actual = array
             .as_primitive::<arrow_array::types::Float64Type>()
@@ -380,7 +389,7 @@ mod tests {
         let values = js_sys::Uint8Array::from([b'a', b'b', b'c', b'd', b'e'].as_slice());
         let offsets = js_sys::Int32Array::from([0i32, 2, 5].as_slice());
         let data = make_data(make_utf8_type(), 2, 0, values.into(), offsets.into());
-        let imported = import_data(data.unchecked_ref()).unwrap();
+        let imported = import_data(data.unchecked_ref(), ValidationLevel::Full).unwrap();
         let array = make_array(imported);
         let actual = array.as_string::<i32>();
         assert_eq!(actual.value(0), "ab");
@@ -397,6 +406,6 @@ mod tests {
             values.into(),
             js_sys::Int32Array::new_with_length(0).into(),
         );
-        assert!(import_data(data.unchecked_ref()).is_err());
+        assert!(import_data(data.unchecked_ref(), ValidationLevel::Full).is_err());
     }
 }
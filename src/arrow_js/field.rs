@@ -2,6 +2,7 @@ use arrow_schema::Field;
 use wasm_bindgen::prelude::*;
 
 use crate::arrow_js::r#type::{import_data_type, JSDataType};
+use crate::error::WasmResult;
 
 #[wasm_bindgen]
 extern "C" {
@@ -21,8 +22,8 @@ extern "C" {
 
 }
 
-pub fn import_field(js_field: &JSField) -> Field {
-    let data_type = import_data_type(&js_field.data_type());
-    Field::new(js_field.name(), data_type, js_field.nullable())
-        .with_metadata(serde_wasm_bindgen::from_value(js_field.metadata().into()).unwrap())
+pub fn import_field(js_field: &JSField) -> WasmResult<Field> {
+    let data_type = import_data_type(&js_field.data_type())?;
+    Ok(Field::new(js_field.name(), data_type, js_field.nullable())
+        .with_metadata(serde_wasm_bindgen::from_value(js_field.metadata().into()).unwrap()))
 }
@@ -10,6 +10,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::arrow_js::data::{import_data, JSData};
 use crate::arrow_js::schema::{import_schema, JSSchema};
+use crate::validation::ValidationLevel;
 
 #[wasm_bindgen]
 extern "C" {
@@ -24,22 +25,30 @@ extern "C" {
 }
 
 impl RecordBatch {
+    /// Creates a record batch from a JSRecordBatch, fully validating the copied data.
     pub fn from_js(js_record_batch: &JSRecordBatch) -> WasmResult<Self> {
-        let schema = import_schema(&js_record_batch.schema());
-        let data = import_data(&js_record_batch.data())?;
-        let dyn_arr = make_array(data);
-        let struct_arr = dyn_arr.as_struct();
+        Self::from_js_with_validation(js_record_batch, ValidationLevel::Full)
+    }
 
-        let batch =
-            arrow_array::RecordBatch::try_new(Arc::new(schema), struct_arr.columns().to_vec())?;
-        Ok(RecordBatch::new(batch))
+    /// Creates a record batch from a JSRecordBatch, validating the copied data at the given
+    /// level.
+    ///
+    /// Lower validation levels are faster, but must only be used for record batches from a
+    /// trusted source.
+    pub fn from_js_with_validation(
+        js_record_batch: &JSRecordBatch,
+        validation: ValidationLevel,
+    ) -> WasmResult<Self> {
+        let schema = Arc::new(import_schema(&js_record_batch.schema())?);
+        Self::from_js_with_schema(js_record_batch, schema, validation)
     }
 
     pub fn from_js_with_schema(
         js_record_batch: &JSRecordBatch,
         schema: SchemaRef,
+        validation: ValidationLevel,
     ) -> WasmResult<Self> {
-        let data = import_data(&js_record_batch.data())?;
+        let data = import_data(&js_record_batch.data(), validation)?;
         let dyn_arr = make_array(data);
         let struct_arr = dyn_arr.as_struct();
 
@@ -2,6 +2,7 @@ use arrow_schema::Schema;
 use wasm_bindgen::prelude::*;
 
 use crate::arrow_js::field::{import_field, JSField};
+use crate::error::WasmResult;
 
 #[wasm_bindgen]
 extern "C" {
@@ -15,14 +16,14 @@ extern "C" {
 
 }
 
-pub fn import_schema(js_schema: &JSSchema) -> Schema {
-    let fields: Vec<_> = js_schema
+pub fn import_schema(js_schema: &JSSchema) -> WasmResult<Schema> {
+    let fields = js_schema
         .fields()
         .into_iter()
         .map(|js_field| import_field(&js_field))
-        .collect();
-    Schema::new_with_metadata(
+        .collect::<WasmResult<Vec<_>>>()?;
+    Ok(Schema::new_with_metadata(
         fields,
         serde_wasm_bindgen::from_value(js_schema.metadata().into()).unwrap(),
-    )
+    ))
 }
@@ -5,6 +5,7 @@ use wasm_bindgen::prelude::*;
 use crate::arrow_js::record_batch::JSRecordBatch;
 use crate::arrow_js::schema::{import_schema, JSSchema};
 use crate::error::WasmResult;
+use crate::validation::ValidationLevel;
 use crate::{RecordBatch, Table};
 
 #[wasm_bindgen]
@@ -20,13 +21,27 @@ extern "C" {
 }
 
 impl Table {
-    /// Creates a table from a JSTable.
+    /// Creates a table from a JSTable, fully validating the copied data.
     pub fn from_js(js_table: &JSTable) -> WasmResult<Table> {
-        let schema = Arc::new(import_schema(&js_table.schema()));
+        Self::from_js_with_validation(js_table, ValidationLevel::Full)
+    }
+
+    /// Creates a table from a JSTable, validating the copied data at the given level.
+    ///
+    /// Lower validation levels are faster, but must only be used for tables from a trusted
+    /// source.
+    pub fn from_js_with_validation(
+        js_table: &JSTable,
+        validation: ValidationLevel,
+    ) -> WasmResult<Table> {
+        let schema = Arc::new(import_schema(&js_table.schema())?);
         let batches = js_table
             .batches()
             .into_iter()
-            .map(|batch| Ok(RecordBatch::from_js_with_schema(&batch, schema.clone())?.into_inner()))
+            .map(|batch| {
+                let batch = RecordBatch::from_js_with_schema(&batch, schema.clone(), validation)?;
+                Ok(batch.into_inner())
+            })
             .collect::<WasmResult<Vec<_>>>()?;
         Ok(Table::new(schema, batches))
     }
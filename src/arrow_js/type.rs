@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use arrow_schema::{DataType, UnionFields};
+use arrow_schema::{ArrowError, DataType, UnionFields};
 use wasm_bindgen::prelude::*;
 
 use crate::arrow_js::field::{import_field, JSField};
+use crate::error::WasmResult;
 
 #[wasm_bindgen]
 extern "C" {
@@ -208,69 +209,76 @@ fn import_duration(js_type: &JSDuration) -> DataType {
     }
 }
 
-fn import_list(js_type: &JSList) -> DataType {
+fn import_list(js_type: &JSList) -> WasmResult<DataType> {
     let mut children = js_type.children();
     assert_eq!(children.len(), 1);
     let child = children.pop().unwrap();
-    let field = import_field(&child);
-    DataType::List(Arc::new(field))
+    let field = import_field(&child)?;
+    Ok(DataType::List(Arc::new(field)))
 }
 
-fn import_struct(js_type: &JSStruct) -> DataType {
+fn import_struct(js_type: &JSStruct) -> WasmResult<DataType> {
     let fields = js_type
         .children()
         .into_iter()
         .map(|child| import_field(&child))
-        .collect();
-    DataType::Struct(fields)
+        .collect::<WasmResult<Vec<_>>>()?;
+    Ok(DataType::Struct(fields.into()))
 }
 
-fn import_union(js_type: &JSUnion) -> DataType {
+fn import_union(js_type: &JSUnion) -> WasmResult<DataType> {
     use super::r#enum::UnionMode;
 
-    let fields: Vec<arrow_schema::Field> = js_type
+    let fields = js_type
         .children()
         .into_iter()
         .map(|child| import_field(&child))
-        .collect();
-    let type_ids: Vec<i8> = js_type
+        .collect::<WasmResult<Vec<_>>>()?;
+    let type_ids = js_type
         .type_ids()
         .to_vec()
         .into_iter()
-        .map(|val| i8::try_from(val).unwrap())
-        .collect();
-
-    let union_fields = UnionFields::new(type_ids, fields);
-    match js_type.mode() {
+        .map(|val| {
+            i8::try_from(val).map_err(|_| {
+                ArrowError::InvalidArgumentError(format!("Union type id {val} is out of range"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let union_fields = UnionFields::try_new(type_ids, fields)?;
+    Ok(match js_type.mode() {
         UnionMode::Dense => DataType::Union(union_fields, arrow_schema::UnionMode::Dense),
         UnionMode::Sparse => DataType::Union(union_fields, arrow_schema::UnionMode::Sparse),
-    }
+    })
 }
 
 fn import_fixed_size_binary(js_type: &JSFixedSizeBinary) -> DataType {
     DataType::FixedSizeBinary(js_type.byte_width())
 }
 
-fn import_fixed_size_list(js_type: &JSFixedSizeList) -> DataType {
+fn import_fixed_size_list(js_type: &JSFixedSizeList) -> WasmResult<DataType> {
     let mut children = js_type.children();
     assert_eq!(children.len(), 1);
     let child = children.pop().unwrap();
-    let field = import_field(&child);
-    DataType::FixedSizeList(Arc::new(field), js_type.list_size())
+    let field = import_field(&child)?;
+    Ok(DataType::FixedSizeList(
+        Arc::new(field),
+        js_type.list_size(),
+    ))
 }
 
-fn import_map(js_type: &JSMap_) -> DataType {
+fn import_map(js_type: &JSMap_) -> WasmResult<DataType> {
     let mut children = js_type.children();
     assert_eq!(children.len(), 1);
     let child = children.pop().unwrap();
-    let field = import_field(&child);
-    DataType::Map(Arc::new(field), js_type.keys_sorted())
+    let field = import_field(&child)?;
+    Ok(DataType::Map(Arc::new(field), js_type.keys_sorted()))
 }
 
-pub fn import_data_type(js_type: &JSDataType) -> DataType {
+pub fn import_data_type(js_type: &JSDataType) -> WasmResult<DataType> {
     use super::r#enum::Type;
 
-    match js_type.type_id() {
+    Ok(match js_type.type_id() {
         // Type None should never be initialized
         Type::NONE => panic!("Type None"),
         Type::Null => DataType::Null,
@@ -285,11 +293,11 @@ pub fn import_data_type(js_type: &JSDataType) -> DataType {
         Type::Timestamp => import_timestamp(js_type.unchecked_ref()),
         Type::Interval => import_interval(js_type.unchecked_ref()),
         Type::Duration => import_duration(js_type.unchecked_ref()),
-        Type::List => import_list(js_type.unchecked_ref()),
-        Type::Struct => import_struct(js_type.unchecked_ref()),
-        Type::Union => import_union(js_type.unchecked_ref()),
+        Type::List => import_list(js_type.unchecked_ref())?,
+        Type::Struct => import_struct(js_type.unchecked_ref())?,
+        Type::Union => import_union(js_type.unchecked_ref())?,
         Type::FixedSizeBinary => import_fixed_size_binary(js_type.unchecked_ref()),
-        Type::FixedSizeList => import_fixed_size_list(js_type.unchecked_ref()),
-        Type::Map => import_map(js_type.unchecked_ref()),
-    }
+        Type::FixedSizeList => import_fixed_size_list(js_type.unchecked_ref())?,
+        Type::Map => import_map(js_type.unchecked_ref())?,
+    })
 }
@@ -0,0 +1,211 @@
+//! Selecting and renaming columns of a schema by name or by index.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchOptions};
+use arrow_schema::{ArrowError, Field, Schema, SchemaRef};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::{Result, WasmResult};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_ColumnSelector: &'static str = r#"
+export type ColumnSelector = string | number;
+export type ColumnRenames = Record<string, string>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ColumnSelector[]")]
+    pub type ColumnSelectors;
+
+    #[wasm_bindgen(typescript_type = "ColumnRenames")]
+    pub type ColumnRenames;
+}
+
+/// A reference to a column of a schema, either by its name or by its positional index.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnSelector {
+    Index(usize),
+    Name(String),
+}
+
+impl ColumnSelector {
+    /// Parse an array of column names and indices from JS.
+    pub fn from_js_array(columns: ColumnSelectors) -> WasmResult<Vec<Self>> {
+        Ok(serde_wasm_bindgen::from_value(columns.into())?)
+    }
+
+    /// Returns the positional index of the selected column in `schema`.
+    pub fn index(&self, schema: &Schema) -> Result<usize> {
+        match self {
+            Self::Index(index) if *index < schema.fields().len() => Ok(*index),
+            Self::Index(index) => Err(ArrowError::InvalidArgumentError(format!(
+                "Column index {index} out of range for schema with {} fields",
+                schema.fields().len()
+            ))
+            .into()),
+            Self::Name(name) => Ok(schema.index_of(name)?),
+        }
+    }
+}
+
+/// Returns the positional indices of the selected columns in `schema`, in the order they were
+/// selected.
+///
+/// Returns an error if a column does not exist or is selected more than once.
+pub fn column_indices(schema: &Schema, selectors: &[ColumnSelector]) -> Result<Vec<usize>> {
+    let mut seen = HashSet::with_capacity(selectors.len());
+    selectors
+        .iter()
+        .map(|selector| {
+            let index = selector.index(schema)?;
+            if !seen.insert(index) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Column {:?} selected more than once",
+                    schema.field(index).name()
+                ))
+                .into());
+            }
+            Ok(index)
+        })
+        .collect()
+}
+
+/// Returns the positional indices of the columns of `schema` that are not selected, in schema
+/// order.
+///
+/// Returns an error if a column does not exist or is selected more than once.
+pub fn remaining_column_indices(
+    schema: &Schema,
+    selectors: &[ColumnSelector],
+) -> Result<Vec<usize>> {
+    let dropped = column_indices(schema, selectors)?;
+    Ok((0..schema.fields().len())
+        .filter(|index| !dropped.contains(index))
+        .collect())
+}
+
+/// Parse a mapping from current to new column names from JS.
+pub fn renames_from_js(renames: ColumnRenames) -> WasmResult<HashMap<String, String>> {
+    Ok(serde_wasm_bindgen::from_value(renames.into())?)
+}
+
+/// Returns a copy of `schema` with the columns named in `renames` renamed, keeping field and
+/// schema metadata.
+///
+/// Returns an error if a column to rename does not exist, or if a new name is shared with any other
+/// column of the result.
+pub fn rename_columns(schema: &Schema, renames: &HashMap<String, String>) -> Result<SchemaRef> {
+    if let Some(name) = renames.keys().find(|name| schema.index_of(name).is_err()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot rename column {name:?}: no such column"
+        ))
+        .into());
+    }
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| match renames.get(field.name()) {
+            Some(name) => Arc::new(field.as_ref().clone().with_name(name)),
+            None => field.clone(),
+        })
+        .collect::<Vec<Arc<Field>>>();
+    let new_names = renames.values().collect::<HashSet<_>>();
+    if let Some(name) = new_names
+        .into_iter()
+        .find(|name| fields.iter().filter(|field| field.name() == *name).count() > 1)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot rename columns: more than one column would be named {name:?}"
+        ))
+        .into());
+    }
+    Ok(Arc::new(Schema::new_with_metadata(
+        fields,
+        schema.metadata().clone(),
+    )))
+}
+
+/// Returns `batch` with its schema replaced by `schema`, which must only differ in field names.
+pub fn with_renamed_columns(batch: &RecordBatch, schema: SchemaRef) -> Result<RecordBatch> {
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        schema,
+        batch.columns().to_vec(),
+        &options,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::DataType;
+
+    use super::*;
+
+    #[test]
+    fn select_columns() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, true),
+        ]);
+        let selectors = [
+            ColumnSelector::Name("c".to_string()),
+            ColumnSelector::Index(0),
+        ];
+        assert_eq!(column_indices(&schema, &selectors).unwrap(), vec![2, 0]);
+
+        assert!(column_indices(&schema, &[ColumnSelector::Index(3)]).is_err());
+        assert!(column_indices(&schema, &[ColumnSelector::Name("d".to_string())]).is_err());
+
+        // The same column selected by name and by index
+        let selectors = [
+            ColumnSelector::Name("b".to_string()),
+            ColumnSelector::Index(1),
+        ];
+        assert!(column_indices(&schema, &selectors).is_err());
+    }
+
+    #[test]
+    fn drop_and_rename_columns() {
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+                Field::new("c", DataType::Float64, true),
+            ],
+            HashMap::from([("key".to_string(), "value".to_string())]),
+        );
+        let selectors = [ColumnSelector::Index(1)];
+        assert_eq!(
+            remaining_column_indices(&schema, &selectors).unwrap(),
+            vec![0, 2]
+        );
+        assert!(remaining_column_indices(&schema, &[ColumnSelector::Index(3)]).is_err());
+
+        let renames = HashMap::from([
+            ("a".to_string(), "x".to_string()),
+            ("c".to_string(), "a".to_string()),
+        ]);
+        let renamed = rename_columns(&schema, &renames).unwrap();
+        let names: Vec<_> = renamed.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, ["x", "b", "a"]);
+        assert_eq!(renamed.metadata(), schema.metadata());
+
+        let renames = HashMap::from([("d".to_string(), "x".to_string())]);
+        assert!(rename_columns(&schema, &renames).is_err());
+
+        // Renames must not create duplicate column names
+        let renames = HashMap::from([("a".to_string(), "b".to_string())]);
+        assert!(rename_columns(&schema, &renames).is_err());
+        let renames = HashMap::from([
+            ("a".to_string(), "x".to_string()),
+            ("b".to_string(), "x".to_string()),
+        ]);
+        assert!(rename_columns(&schema, &renames).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, RecordBatch};
+use arrow_buffer::{BooleanBuffer, Buffer};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::concat::concat;
+use arrow_select::filter::filter_record_batch;
+
+use crate::error::Result;
+
+/// Combine the chunks of a boolean column into a single mask.
+///
+/// Returns an error if a chunk is not a boolean array.
+pub fn mask_from_chunks(chunks: &[ArrayRef]) -> Result<BooleanArray> {
+    if let Some(chunk) = chunks
+        .iter()
+        .find(|chunk| chunk.data_type() != &DataType::Boolean)
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter mask must be a boolean array, found {}",
+            chunk.data_type()
+        ))
+        .into());
+    }
+    match chunks {
+        [chunk] => Ok(chunk.as_boolean().clone()),
+        [] => Ok(BooleanArray::from(Vec::<bool>::new())),
+        chunks => {
+            let chunks = chunks
+                .iter()
+                .map(|chunk| chunk.as_ref())
+                .collect::<Vec<_>>();
+            Ok(concat(&chunks)?.as_boolean().clone())
+        }
+    }
+}
+
+/// Create a mask of `len` rows from a bitmap, where the bit of row `i` is bit `i % 8` of byte
+/// `i / 8`, as in the validity bitmaps of the Arrow format.
+pub fn mask_from_bitmap(bitmap: &[u8], len: usize) -> Result<BooleanArray> {
+    if bitmap.len() * 8 < len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter bitmap of {} bytes is too short for {len} rows",
+            bitmap.len()
+        ))
+        .into());
+    }
+    let values = BooleanBuffer::new(Buffer::from_slice_ref(bitmap), 0, len);
+    Ok(BooleanArray::new(values, None))
+}
+
+/// Keep the rows of `batch` where `mask` is `true`. Null mask values drop their row.
+pub fn filter_batch(batch: &RecordBatch, mask: &BooleanArray) -> Result<RecordBatch> {
+    check_mask_length(mask, batch.num_rows())?;
+    Ok(filter_record_batch(batch, mask)?)
+}
+
+/// Keep the rows of `batches` where `mask` is `true`, treating the batches as a single sequence of
+/// rows. Batches left without any rows are removed.
+pub fn filter_batches(batches: &[RecordBatch], mask: &BooleanArray) -> Result<Vec<RecordBatch>> {
+    check_mask_length(mask, batches.iter().map(|batch| batch.num_rows()).sum())?;
+    let mut offset = 0;
+    let mut filtered = vec![];
+    for batch in batches {
+        let batch_mask = mask.slice(offset, batch.num_rows());
+        offset += batch.num_rows();
+        let batch = filter_record_batch(batch, &batch_mask)?;
+        if batch.num_rows() > 0 {
+            filtered.push(batch);
+        }
+    }
+    Ok(filtered)
+}
+
+fn check_mask_length(mask: &BooleanArray, num_rows: usize) -> Result<()> {
+    if mask.len() != num_rows {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter mask has {} values but there are {num_rows} rows",
+            mask.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, StringArray};
+
+    use super::*;
+
+    fn batch(values: Vec<i32>) -> RecordBatch {
+        let strings = values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(values)) as ArrayRef),
+            ("b", Arc::new(StringArray::from(strings)) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn filter_rows() {
+        let mask = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(
+            filter_batch(&batch(vec![0, 1, 2, 3]), &mask).unwrap(),
+            batch(vec![0, 3])
+        );
+        assert!(filter_batch(&batch(vec![0, 1]), &mask).is_err());
+
+        // The mask is split at batch boundaries, and emptied batches are removed
+        let batches = [batch(vec![0, 1]), batch(vec![2]), batch(vec![3])];
+        assert_eq!(
+            filter_batches(&batches, &mask).unwrap(),
+            [batch(vec![0]), batch(vec![3])]
+        );
+        assert!(filter_batches(&batches[1..], &mask).is_err());
+    }
+
+    #[test]
+    fn masks() {
+        let mask = mask_from_bitmap(&[0b0000_1001], 4).unwrap();
+        assert_eq!(mask, BooleanArray::from(vec![true, false, false, true]));
+        assert!(mask_from_bitmap(&[0xff], 9).is_err());
+
+        let chunks = [
+            Arc::new(BooleanArray::from(vec![true])) as ArrayRef,
+            Arc::new(BooleanArray::from(vec![false, true])) as ArrayRef,
+        ];
+        let mask = mask_from_chunks(&chunks).unwrap();
+        assert_eq!(mask, BooleanArray::from(vec![true, false, true]));
+        assert!(mask_from_chunks(&[Arc::new(Int32Array::from(vec![1])) as ArrayRef]).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+//! Kernels that select, reorder and sort the rows of record batches.
+//!
+//! These apply the same operation to every column of a batch, and back the row-level methods of
+//! `Table` and `RecordBatch`.
+
+mod filter;
+mod sort;
+mod take;
+
+pub use filter::{filter_batch, filter_batches, mask_from_bitmap, mask_from_chunks};
+pub use sort::{sort_to_indices, SortKey, SortKeys};
+pub use take::{take_batch, take_batches};
@@ -0,0 +1,146 @@
+use arrow_array::RecordBatch;
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::{ArrowError, Schema, SortOptions};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::column::ColumnSelector;
+use crate::error::{Result, WasmResult};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_SortKey: &'static str = r#"
+export type SortKey = {
+  column: ColumnSelector;
+  descending?: boolean;
+  nullsFirst?: boolean;
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "SortKey[]")]
+    pub type SortKeys;
+}
+
+/// A column to sort by and the order to sort it in.
+///
+/// Rows are sorted in ascending order unless `descending` is set. Nulls sort before all other
+/// values unless `nulls_first` is `false`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SortKey {
+    pub column: ColumnSelector,
+    #[serde(default)]
+    pub descending: bool,
+    pub nulls_first: Option<bool>,
+}
+
+impl SortKey {
+    /// Parse an array of sort keys from JS.
+    pub fn from_js_array(keys: SortKeys) -> WasmResult<Vec<Self>> {
+        Ok(serde_wasm_bindgen::from_value(keys.into())?)
+    }
+
+    fn options(&self) -> SortOptions {
+        SortOptions {
+            descending: self.descending,
+            nulls_first: self.nulls_first.unwrap_or(true),
+        }
+    }
+}
+
+/// Returns the indices of the rows of `batches` in lexicographic order of `keys`, treating the
+/// batches as a single sequence of rows.
+///
+/// The sort is stable, so rows that compare equal keep their relative order. The sort columns are
+/// first converted to the row format, so every comparison is a byte comparison regardless of the
+/// number and types of the columns.
+pub fn sort_to_indices(
+    schema: &Schema,
+    batches: &[RecordBatch],
+    keys: &[SortKey],
+) -> Result<Vec<u32>> {
+    let num_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+    let num_rows = u32::try_from(num_rows)
+        .map_err(|_| ArrowError::InvalidArgumentError(format!("Cannot sort {num_rows} rows")))?;
+    let mut indices = (0..num_rows).collect::<Vec<_>>();
+    if keys.is_empty() {
+        return Ok(indices);
+    }
+
+    let columns = keys
+        .iter()
+        .map(|key| key.column.index(schema))
+        .collect::<Result<Vec<_>>>()?;
+    let fields = keys
+        .iter()
+        .zip(&columns)
+        .map(|(key, column)| {
+            SortField::new_with_options(schema.field(*column).data_type().clone(), key.options())
+        })
+        .collect();
+    let converter = RowConverter::new(fields)?;
+    let mut rows = converter.empty_rows(num_rows as usize, 0);
+    for batch in batches {
+        let columns = columns
+            .iter()
+            .map(|column| batch.column(*column).clone())
+            .collect::<Vec<_>>();
+        converter.append(&mut rows, &columns)?;
+    }
+
+    indices.sort_by(|a, b| rows.row(*a as usize).cmp(&rows.row(*b as usize)));
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Int32Array, StringArray};
+
+    use super::*;
+
+    fn batch(a: Vec<Option<i32>>, b: Vec<&str>) -> RecordBatch {
+        RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(a)) as ArrayRef),
+            ("b", Arc::new(StringArray::from(b)) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    fn key(column: &str, descending: bool, nulls_first: Option<bool>) -> SortKey {
+        SortKey {
+            column: ColumnSelector::Name(column.to_string()),
+            descending,
+            nulls_first,
+        }
+    }
+
+    #[test]
+    fn sort_across_batches() {
+        let batches = [
+            batch(vec![Some(2), None], vec!["x", "y"]),
+            batch(vec![Some(1), Some(2), Some(1)], vec!["z", "w", "z"]),
+        ];
+        let schema = batches[0].schema();
+
+        let keys = [key("a", false, None)];
+        assert_eq!(
+            sort_to_indices(&schema, &batches, &keys).unwrap(),
+            [1, 2, 4, 0, 3]
+        );
+
+        let keys = [key("a", true, Some(false)), key("b", false, None)];
+        assert_eq!(
+            sort_to_indices(&schema, &batches, &keys).unwrap(),
+            [3, 0, 2, 4, 1]
+        );
+
+        assert_eq!(
+            sort_to_indices(&schema, &batches, &[]).unwrap(),
+            [0, 1, 2, 3, 4]
+        );
+        assert!(sort_to_indices(&schema, &batches, &[key("c", false, None)]).is_err());
+    }
+}
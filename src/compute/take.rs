@@ -0,0 +1,189 @@
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, ArrowPrimitiveType, RecordBatch, RecordBatchOptions};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, SchemaRef};
+use arrow_select::concat::concat_batches;
+use arrow_select::interleave::interleave;
+use arrow_select::take::{take, TakeOptions};
+
+use crate::error::Result;
+
+/// Gather the rows of `batch` at `indices`, an array of any integer type.
+///
+/// Null indices produce null rows. Returns an error if an index is negative or out of bounds.
+pub fn take_batch(batch: &RecordBatch, indices: &dyn Array) -> Result<RecordBatch> {
+    let options = TakeOptions { check_bounds: true };
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| take(column, indices, Some(options.clone())))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(RecordBatch::try_new_with_options(
+        batch.schema(),
+        columns,
+        &RecordBatchOptions::new().with_row_count(Some(indices.len())),
+    )?)
+}
+
+/// Gather the rows at `indices` of `batches`, treating them as a single sequence of rows, into a
+/// single batch.
+///
+/// Rows are copied directly out of each batch, without first concatenating the batches, unless
+/// some of the indices are null.
+pub fn take_batches(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+    indices: &dyn Array,
+) -> Result<RecordBatch> {
+    if let [batch] = batches {
+        return take_batch(batch, indices);
+    }
+    let values = index_values(indices)?;
+    if batches.is_empty() || values.iter().any(Option::is_none) {
+        return take_batch(&concat_batches(schema, batches)?, indices);
+    }
+
+    // The index of the first row of each batch
+    let mut starts = Vec::with_capacity(batches.len());
+    let mut num_rows = 0;
+    for batch in batches {
+        starts.push(num_rows);
+        num_rows += batch.num_rows();
+    }
+    let rows = values
+        .into_iter()
+        .flatten()
+        .map(|index| {
+            if index >= num_rows {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Row index {index} out of range for {num_rows} rows"
+                )));
+            }
+            let batch = starts.partition_point(|start| *start <= index) - 1;
+            Ok((batch, index - starts[batch]))
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let columns = (0..schema.fields().len())
+        .map(|i| {
+            let arrays = batches
+                .iter()
+                .map(|batch| batch.column(i).as_ref())
+                .collect::<Vec<_>>();
+            interleave(&arrays, &rows)
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(RecordBatch::try_new_with_options(
+        schema.clone(),
+        columns,
+        &RecordBatchOptions::new().with_row_count(Some(rows.len())),
+    )?)
+}
+
+/// Returns the values of an integer array of indices, or an error if one is negative.
+fn index_values(indices: &dyn Array) -> Result<Vec<Option<usize>>> {
+    match indices.data_type() {
+        DataType::Int8 => primitive_index_values::<Int8Type>(indices),
+        DataType::Int16 => primitive_index_values::<Int16Type>(indices),
+        DataType::Int32 => primitive_index_values::<Int32Type>(indices),
+        DataType::Int64 => primitive_index_values::<Int64Type>(indices),
+        DataType::UInt8 => primitive_index_values::<UInt8Type>(indices),
+        DataType::UInt16 => primitive_index_values::<UInt16Type>(indices),
+        DataType::UInt32 => primitive_index_values::<UInt32Type>(indices),
+        DataType::UInt64 => primitive_index_values::<UInt64Type>(indices),
+        data_type => Err(ArrowError::InvalidArgumentError(format!(
+            "Indices must be an integer array, found {data_type}"
+        ))
+        .into()),
+    }
+}
+
+fn primitive_index_values<T: ArrowPrimitiveType>(
+    indices: &dyn Array,
+) -> Result<Vec<Option<usize>>> {
+    indices
+        .as_primitive::<T>()
+        .iter()
+        .map(|index| {
+            index
+                .map(|index| {
+                    index.to_usize().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!("Invalid row index {index:?}"))
+                            .into()
+                    })
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, Int64Array, UInt32Array};
+
+    use super::*;
+
+    fn batch(values: Vec<i32>) -> RecordBatch {
+        let strings = values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        let dictionary: DictionaryArray<Int32Type> = strings.iter().map(String::as_str).collect();
+        RecordBatch::try_from_iter_with_nullable([
+            ("a", Arc::new(Int32Array::from(values)) as ArrayRef, true),
+            ("b", Arc::new(dictionary) as ArrayRef, true),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn take_rows() {
+        let indices = UInt32Array::from(vec![3, 0, 3]);
+        assert_eq!(
+            take_batch(&batch(vec![0, 1, 2, 3]), &indices).unwrap(),
+            batch(vec![3, 0, 3])
+        );
+        assert!(take_batch(&batch(vec![0, 1]), &indices).is_err());
+        let indices = Int64Array::from(vec![-1]);
+        assert!(take_batch(&batch(vec![0, 1]), &indices).is_err());
+    }
+
+    #[test]
+    fn take_rows_across_batches() {
+        let batches = [batch(vec![0, 1]), batch(vec![2]), batch(vec![3, 4])];
+        let schema = batches[0].schema();
+        let taken = take_batches(&schema, &batches, &UInt32Array::from(vec![4, 2, 0, 3])).unwrap();
+        let expected = take_batch(
+            &batch(vec![0, 1, 2, 3, 4]),
+            &UInt32Array::from(vec![4, 2, 0, 3]),
+        );
+        assert_eq!(taken, expected.unwrap());
+        assert_eq!(
+            taken.column(0).as_ref(),
+            &Int32Array::from(vec![4, 2, 0, 3])
+        );
+
+        // Null indices produce null rows
+        let indices = Int64Array::from(vec![Some(1), None]);
+        let taken = take_batches(&schema, &batches, &indices).unwrap();
+        assert_eq!(
+            taken.column(0).as_ref(),
+            &Int32Array::from(vec![Some(1), None])
+        );
+
+        assert!(take_batches(&schema, &batches, &UInt32Array::from(vec![5])).is_err());
+        assert!(take_batches(&schema, &batches, &Int64Array::from(vec![-1])).is_err());
+        assert_eq!(
+            take_batches(&schema, &[], &UInt32Array::from(Vec::<u32>::new()))
+                .unwrap()
+                .num_rows(),
+            0
+        );
+    }
+}
@@ -11,7 +11,9 @@ use arrow_schema::{Field, FieldRef};
 use wasm_bindgen::prelude::*;
 
 use crate::error::{ArrowWasmError, WasmResult};
-use crate::ffi::FFIData;
+use crate::ffi::layout::{ArrayLayout, BufferLayout};
+use crate::ffi::{FFIData, FFIDeviceData};
+use crate::validation::{Validation, ValidationLevel};
 
 /// A representation of an Arrow `Data` instance in WebAssembly memory.
 ///
@@ -24,12 +26,60 @@ pub struct Data {
 
 #[wasm_bindgen]
 impl Data {
+    /// Import a `Data` from an `ArrowArray` and `ArrowSchema` pair located in this instance's
+    /// WebAssembly memory, according to the Arrow C Data Interface.
+    ///
+    /// This takes ownership of both structs: they are moved out of their original location and
+    /// marked as released, and the producer's release callback will be called once this `Data`
+    /// is freed.
+    ///
+    /// @param arrayAddr number pointer to an ArrowArray FFI struct in Wasm memory
+    /// @param schemaAddr number pointer to an ArrowSchema FFI struct in Wasm memory
+    /// @param validation how thoroughly to check the imported data, `"full"` by default
+    #[wasm_bindgen(js_name = fromFFI)]
+    pub fn from_ffi(
+        array_addr: usize,
+        schema_addr: usize,
+        validation: Option<Validation>,
+    ) -> WasmResult<Data> {
+        let validation = ValidationLevel::from_js(validation)?;
+        let (array, field) = unsafe {
+            crate::ffi::import::import_array(
+                array_addr as *mut _,
+                schema_addr as *mut _,
+                validation,
+            )
+        }?;
+        Ok(Self::new(array, Arc::new(field)))
+    }
+
     /// Export this to FFI.
     #[wasm_bindgen(js_name = toFFI)]
     pub fn to_ffi(&self) -> WasmResult<FFIData> {
-        let ffi_schema = arrow_array::ffi::FFI_ArrowSchema::try_from(&self.field)?;
-        let ffi_array = arrow_array::ffi::FFI_ArrowArray::new(&self.array.to_data());
-        Ok(FFIData::new(Box::new(ffi_array), Box::new(ffi_schema)))
+        Ok(FFIData::from_arrow(&self.array, self.field.as_ref())?)
+    }
+
+    /// Export this to FFI according to the Arrow C Device Data Interface.
+    ///
+    /// The returned {@linkcode FFIDeviceData} holds an `ArrowDeviceArray` struct with
+    /// `device_type` set to `ARROW_DEVICE_CPU`.
+    #[wasm_bindgen(js_name = toFFIDevice)]
+    pub fn to_ffi_device(&self) -> WasmResult<FFIDeviceData> {
+        Ok(self.to_ffi()?.into())
+    }
+
+    /// Describe the physical layout of this `Data` in WebAssembly memory.
+    ///
+    /// This returns a plain JS object with the data type, length, offset and null count of the
+    /// array, plus a `{ptr, byteLength}` region into {@linkcode wasmMemory} for the validity
+    /// bitmap and each buffer, recursively for children and dictionaries. This makes it possible to
+    /// create TypedArray views directly over WebAssembly memory without walking C structs.
+    ///
+    /// The regions are only valid as long as this `Data` is alive, and views over them are
+    /// invalidated if WebAssembly memory grows.
+    #[wasm_bindgen(js_name = bufferLayout)]
+    pub fn buffer_layout(&self) -> WasmResult<BufferLayout> {
+        ArrayLayout::from(&self.array.to_data()).to_js()
     }
 
     /// Copy the values of this `Data` instance to a TypedArray in the JavaScript heap.
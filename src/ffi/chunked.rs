@@ -1,31 +1,72 @@
-use arrow_array::ffi;
+use arrow_array::{ffi, Array, ArrayRef};
+use arrow_schema::ArrowError;
 use wasm_bindgen::convert::IntoWasmAbi;
 use wasm_bindgen::prelude::*;
 
-use crate::ffi::FFISchema;
+use crate::error::Result;
+use crate::ffi::registry::{register, FFIExportKind, Registration};
 
 /// A representation of an Arrow C Stream in WebAssembly memory exposed as FFI-compatible
 /// structs through the Arrow C Data Interface.
 ///
 /// Unlike other Arrow implementations outside of JS, this always stores the "stream" fully
-/// materialized as a sequence of Arrow chunks.
+/// materialized as a sequence of Arrow chunks. Use {@linkcode FFIArrowArrayStream} for a stream
+/// conforming to the Arrow C Stream Interface.
 #[wasm_bindgen]
 pub struct FFIStream {
-    pub(crate) field: FFISchema,
-    pub(crate) arrays: Vec<ffi::FFI_ArrowArray>,
+    registration: Registration,
+    field: Box<ffi::FFI_ArrowSchema>,
+    arrays: Vec<ffi::FFI_ArrowArray>,
 }
 
 impl FFIStream {
-    pub fn new(field: Box<ffi::FFI_ArrowSchema>, arrays: Vec<ffi::FFI_ArrowArray>) -> Self {
+    pub(crate) fn new(
+        mut field: Box<ffi::FFI_ArrowSchema>,
+        mut arrays: Vec<ffi::FFI_ArrowArray>,
+        byte_size: usize,
+    ) -> Self {
+        let field_ptr = field.as_mut() as *mut ffi::FFI_ArrowSchema;
+        let arrays_ptr = arrays.as_mut_ptr();
+        let num_arrays = arrays.len();
+        let registration = register(FFIExportKind::Stream, byte_size, move || unsafe {
+            drop(ffi::FFI_ArrowSchema::from_raw(field_ptr));
+            for i in 0..num_arrays {
+                drop(ffi::FFI_ArrowArray::from_raw(arrays_ptr.add(i)));
+            }
+        });
         Self {
-            field: FFISchema::new(field),
+            registration,
+            field,
             arrays,
         }
     }
+
+    /// Construct an [FFIStream] from a field and a sequence of arrays matching that field.
+    pub fn from_arrow(
+        field: impl TryInto<ffi::FFI_ArrowSchema, Error = ArrowError>,
+        arrays: &[ArrayRef],
+    ) -> Result<Self> {
+        let ffi_field: ffi::FFI_ArrowSchema = field.try_into()?;
+        let ffi_arrays = arrays
+            .iter()
+            .map(|array| ffi::FFI_ArrowArray::new(&array.to_data()))
+            .collect();
+        let byte_size = arrays
+            .iter()
+            .map(|array| array.get_array_memory_size())
+            .sum();
+        Ok(Self::new(Box::new(ffi_field), ffi_arrays, byte_size))
+    }
 }
 
 #[wasm_bindgen]
 impl FFIStream {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.registration.byte_size()
+    }
+
     /// Get the total number of elements in this stream
     #[wasm_bindgen(js_name = numArrays)]
     pub fn num_arrays(&self) -> usize {
@@ -35,7 +76,7 @@ impl FFIStream {
     /// Get the pointer to the ArrowSchema FFI struct
     #[wasm_bindgen(js_name = schemaAddr)]
     pub fn schema_addr(&self) -> *const ffi::FFI_ArrowSchema {
-        self.field.addr()
+        self.field.as_ref() as *const _
     }
 
     /// Get the pointer to one ArrowArray FFI struct for a given chunk index and column index
@@ -3,6 +3,7 @@ use arrow_schema::{ArrowError, Field};
 use wasm_bindgen::prelude::*;
 
 use crate::error::Result;
+use crate::ffi::registry::{register, FFIExportKind, Registration};
 use crate::ArrowWasmError;
 
 /// An Arrow array exported to FFI.
@@ -43,13 +44,28 @@ use crate::ArrowWasmError;
 /// release memory.
 #[wasm_bindgen]
 pub struct FFIData {
-    array: Box<ffi::FFI_ArrowArray>,
-    field: Box<ffi::FFI_ArrowSchema>,
+    registration: Registration,
+    pub(crate) array: Box<ffi::FFI_ArrowArray>,
+    pub(crate) field: Box<ffi::FFI_ArrowSchema>,
 }
 
 impl FFIData {
-    pub fn new(array: Box<ffi::FFI_ArrowArray>, field: Box<ffi::FFI_ArrowSchema>) -> Self {
-        Self { array, field }
+    pub(crate) fn new(
+        mut array: Box<ffi::FFI_ArrowArray>,
+        mut field: Box<ffi::FFI_ArrowSchema>,
+        byte_size: usize,
+    ) -> Self {
+        let array_ptr = array.as_mut() as *mut ffi::FFI_ArrowArray;
+        let field_ptr = field.as_mut() as *mut ffi::FFI_ArrowSchema;
+        let registration = register(FFIExportKind::Data, byte_size, move || unsafe {
+            drop(ffi::FFI_ArrowArray::from_raw(array_ptr));
+            drop(ffi::FFI_ArrowSchema::from_raw(field_ptr));
+        });
+        Self {
+            registration,
+            array,
+            field,
+        }
     }
 
     /// Construct an [FFIData] from an Arrow array and optionally a field.
@@ -65,10 +81,11 @@ impl FFIData {
         let ffi_field: arrow_array::ffi::FFI_ArrowSchema = field.try_into()?;
         let ffi_array = arrow_array::ffi::FFI_ArrowArray::new(&array.to_data());
 
-        Ok(Self {
-            array: Box::new(ffi_array),
-            field: Box::new(ffi_field),
-        })
+        Ok(Self::new(
+            Box::new(ffi_array),
+            Box::new(ffi_field),
+            array.get_array_memory_size(),
+        ))
     }
 }
 
@@ -76,12 +93,7 @@ impl TryFrom<&dyn Array> for FFIData {
     type Error = crate::error::ArrowWasmError;
 
     fn try_from(value: &dyn Array) -> Result<Self> {
-        let ffi_field = ffi::FFI_ArrowSchema::try_from(value.data_type())?;
-        let ffi_array = ffi::FFI_ArrowArray::new(&value.to_data());
-        Ok(Self {
-            field: Box::new(ffi_field),
-            array: Box::new(ffi_array),
-        })
+        Self::from_arrow(value, value.data_type())
     }
 }
 
@@ -89,7 +101,8 @@ impl TryFrom<&arrow_array::RecordBatch> for FFIData {
     type Error = ArrowWasmError;
 
     fn try_from(value: &arrow_array::RecordBatch) -> std::result::Result<Self, Self::Error> {
-        let field = Field::new_struct("", value.schema_ref().fields().clone(), false);
+        let field = Field::new_struct("", value.schema_ref().fields().clone(), false)
+            .with_metadata(value.schema_ref().metadata().clone());
         let data = StructArray::from(value.clone());
         Self::from_arrow(&data, field)
     }
@@ -97,6 +110,22 @@ impl TryFrom<&arrow_array::RecordBatch> for FFIData {
 
 #[wasm_bindgen]
 impl FFIData {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.registration.byte_size()
+    }
+
+    /// Convert this to an `ArrowDeviceArray` according to the Arrow C Device Data Interface.
+    ///
+    /// This consumes the FFIData and moves its `ArrowArray` struct into the returned
+    /// {@linkcode FFIDeviceData}, so any address previously returned by {@linkcode
+    /// FFIData.arrayAddr} is no longer valid.
+    #[wasm_bindgen(js_name = intoDevice)]
+    pub fn into_device(self) -> crate::ffi::FFIDeviceData {
+        self.into()
+    }
+
     /// Access the pointer to the
     /// [`ArrowArray`](https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions)
     /// struct. This can be viewed or copied (without serialization) to an Arrow JS `RecordBatch` by
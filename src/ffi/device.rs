@@ -0,0 +1,385 @@
+//! Export to the [Arrow C Device Data
+//! Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html).
+//!
+//! All data in WebAssembly memory lives on the CPU, so every struct exported here has
+//! `device_type` set to [ARROW_DEVICE_CPU].
+
+use std::os::raw::{c_char, c_int, c_void};
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+use wasm_bindgen::prelude::*;
+
+use crate::ffi::registry::{register, FFIExportKind, Registration};
+use crate::ffi::{FFIArrowArrayStream, FFIData};
+
+/// The `ArrowDeviceType` of CPU memory.
+pub const ARROW_DEVICE_CPU: i32 = 1;
+
+/// ABI-compatible struct for `ArrowDeviceArray` from the C Device Data Interface.
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowDeviceArray {
+    pub array: FFI_ArrowArray,
+    pub device_id: i64,
+    pub device_type: i32,
+    pub sync_event: *mut c_void,
+    pub reserved: [i64; 3],
+}
+
+impl FFI_ArrowDeviceArray {
+    /// Wrap an [FFI_ArrowArray] whose buffers live in CPU memory.
+    pub fn new_cpu(array: FFI_ArrowArray) -> Self {
+        Self {
+            array,
+            device_id: -1,
+            device_type: ARROW_DEVICE_CPU,
+            sync_event: std::ptr::null_mut(),
+            reserved: [0; 3],
+        }
+    }
+}
+
+/// ABI-compatible struct for `ArrowDeviceArrayStream` from the C Device Data Interface.
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowDeviceArrayStream {
+    pub device_type: i32,
+    pub get_schema:
+        Option<unsafe extern "C" fn(arg1: *mut Self, out: *mut FFI_ArrowSchema) -> c_int>,
+    pub get_next:
+        Option<unsafe extern "C" fn(arg1: *mut Self, out: *mut FFI_ArrowDeviceArray) -> c_int>,
+    pub get_last_error: Option<unsafe extern "C" fn(arg1: *mut Self) -> *const c_char>,
+    pub release: Option<unsafe extern "C" fn(arg1: *mut Self)>,
+    pub private_data: *mut c_void,
+}
+
+// The device stream delegates every callback to an `FFI_ArrowArrayStream` stored in
+// `private_data`, filling in the device fields of each exported array.
+//
+// Returns `None` once the stream has been released, as `release_stream` clears `private_data`.
+unsafe fn inner_stream(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+) -> Option<*mut FFI_ArrowArrayStream> {
+    if stream.is_null() {
+        return None;
+    }
+    let inner = unsafe { (*stream).private_data as *mut FFI_ArrowArrayStream };
+    (!inner.is_null()).then_some(inner)
+}
+
+unsafe extern "C" fn get_schema(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    out: *mut FFI_ArrowSchema,
+) -> c_int {
+    let inner = match unsafe { inner_stream(stream) } {
+        Some(inner) => inner,
+        None => return einval(),
+    };
+    match unsafe { (*inner).get_schema } {
+        Some(get_schema) => unsafe { get_schema(inner, out) },
+        None => einval(),
+    }
+}
+
+unsafe extern "C" fn get_next(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    out: *mut FFI_ArrowDeviceArray,
+) -> c_int {
+    let inner = match unsafe { inner_stream(stream) } {
+        Some(inner) => inner,
+        None => return einval(),
+    };
+    let get_next = match unsafe { (*inner).get_next } {
+        Some(get_next) => get_next,
+        None => return einval(),
+    };
+
+    // `out` may be uninitialized, so it must be written without dropping its previous value
+    unsafe { std::ptr::write(out, FFI_ArrowDeviceArray::new_cpu(FFI_ArrowArray::empty())) };
+    unsafe { get_next(inner, &mut (*out).array) }
+}
+
+unsafe extern "C" fn get_last_error(stream: *mut FFI_ArrowDeviceArrayStream) -> *const c_char {
+    let inner = match unsafe { inner_stream(stream) } {
+        Some(inner) => inner,
+        None => return std::ptr::null(),
+    };
+    match unsafe { (*inner).get_last_error } {
+        Some(get_last_error) => unsafe { get_last_error(inner) },
+        None => std::ptr::null(),
+    }
+}
+
+unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowDeviceArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = unsafe { &mut *stream };
+
+    stream.get_schema = None;
+    stream.get_next = None;
+    stream.get_last_error = None;
+
+    if !stream.private_data.is_null() {
+        let inner = unsafe { Box::from_raw(stream.private_data as *mut FFI_ArrowArrayStream) };
+        drop(inner);
+    }
+
+    stream.private_data = std::ptr::null_mut();
+    stream.release = None;
+}
+
+fn einval() -> c_int {
+    // EINVAL, as used by the C Stream Interface for invalid calls
+    22
+}
+
+impl FFI_ArrowDeviceArrayStream {
+    /// Creates a new empty, released [FFI_ArrowDeviceArrayStream].
+    pub fn empty() -> Self {
+        Self {
+            device_type: ARROW_DEVICE_CPU,
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: std::ptr::null_mut(),
+        }
+    }
+
+    /// Wrap an [FFI_ArrowArrayStream] whose batches live in CPU memory.
+    pub fn new_cpu(stream: FFI_ArrowArrayStream) -> Self {
+        Self {
+            device_type: ARROW_DEVICE_CPU,
+            get_schema: Some(get_schema),
+            get_next: Some(get_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release_stream),
+            private_data: Box::into_raw(Box::new(stream)) as *mut c_void,
+        }
+    }
+}
+
+impl Drop for FFI_ArrowDeviceArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// An Arrow array exported to FFI according to the Arrow C Device Data Interface.
+///
+/// This is the device-aware equivalent of {@linkcode FFIData}: the array is exposed as an
+/// `ArrowDeviceArray` struct with `device_type` set to `ARROW_DEVICE_CPU`, alongside an
+/// `ArrowSchema` struct describing it.
+///
+/// ## Memory management
+///
+/// Note that this array will not be released automatically. You need to manually call `.free()` to
+/// release memory.
+#[wasm_bindgen]
+pub struct FFIDeviceData {
+    registration: Registration,
+    array: Box<FFI_ArrowDeviceArray>,
+    field: Box<FFI_ArrowSchema>,
+}
+
+impl FFIDeviceData {
+    pub(crate) fn new(
+        mut array: Box<FFI_ArrowDeviceArray>,
+        mut field: Box<FFI_ArrowSchema>,
+        byte_size: usize,
+    ) -> Self {
+        let array_ptr = std::ptr::addr_of_mut!(array.array);
+        let field_ptr = field.as_mut() as *mut FFI_ArrowSchema;
+        let registration = register(FFIExportKind::DeviceData, byte_size, move || unsafe {
+            drop(FFI_ArrowArray::from_raw(array_ptr));
+            drop(FFI_ArrowSchema::from_raw(field_ptr));
+        });
+        Self {
+            registration,
+            array,
+            field,
+        }
+    }
+}
+
+impl From<FFIData> for FFIDeviceData {
+    fn from(value: FFIData) -> Self {
+        let byte_size = value.byte_size();
+        let array = FFI_ArrowDeviceArray::new_cpu(*value.array);
+        Self::new(Box::new(array), value.field, byte_size)
+    }
+}
+
+#[wasm_bindgen]
+impl FFIDeviceData {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.registration.byte_size()
+    }
+
+    /// Access the pointer to the
+    /// [`ArrowDeviceArray`](https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions)
+    /// struct. You can access the
+    /// [`WebAssembly.Memory`](https://developer.mozilla.org/en-US/docs/WebAssembly/JavaScript_interface/Memory)
+    /// instance by using {@linkcode wasmMemory}.
+    #[wasm_bindgen(js_name = arrayAddr)]
+    pub fn array_addr(&self) -> *const FFI_ArrowDeviceArray {
+        self.array.as_ref() as *const _
+    }
+
+    /// Access the pointer to the
+    /// [`ArrowSchema`](https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions)
+    /// struct describing the array.
+    #[wasm_bindgen(js_name = schemaAddr)]
+    pub fn schema_addr(&self) -> *const FFI_ArrowSchema {
+        self.field.as_ref() as *const _
+    }
+
+    /// The `ArrowDeviceType` of this array. This is always `ARROW_DEVICE_CPU` (`1`).
+    #[wasm_bindgen(getter, js_name = deviceType)]
+    pub fn device_type(&self) -> i32 {
+        self.array.device_type
+    }
+}
+
+/// An Arrow C Device Stream in WebAssembly memory conforming to the [Arrow C Device Data
+/// Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html).
+///
+/// This is the device-aware equivalent of {@linkcode FFIArrowArrayStream}: every array returned
+/// by `get_next` is an `ArrowDeviceArray` with `device_type` set to `ARROW_DEVICE_CPU`.
+///
+/// ## Memory management
+///
+/// Note that this stream will not be released automatically. You need to manually call `.free()`
+/// to release memory.
+#[wasm_bindgen]
+pub struct FFIArrowDeviceArrayStream(Registration, Box<FFI_ArrowDeviceArrayStream>);
+
+impl FFIArrowDeviceArrayStream {
+    pub(crate) fn new(mut stream: Box<FFI_ArrowDeviceArrayStream>, byte_size: usize) -> Self {
+        let stream_ptr = stream.as_mut() as *mut FFI_ArrowDeviceArrayStream;
+        let registration = register(
+            FFIExportKind::DeviceArrayStream,
+            byte_size,
+            move || unsafe {
+                drop(std::ptr::replace(
+                    stream_ptr,
+                    FFI_ArrowDeviceArrayStream::empty(),
+                ));
+            },
+        );
+        Self(registration, stream)
+    }
+}
+
+impl From<FFIArrowArrayStream> for FFIArrowDeviceArrayStream {
+    fn from(value: FFIArrowArrayStream) -> Self {
+        let byte_size = value.byte_size();
+        let stream = FFI_ArrowDeviceArrayStream::new_cpu(value.into_inner());
+        Self::new(Box::new(stream), byte_size)
+    }
+}
+
+#[wasm_bindgen]
+impl FFIArrowDeviceArrayStream {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.0.byte_size()
+    }
+
+    /// Access the pointer to the
+    /// [`ArrowDeviceArrayStream`](https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions)
+    /// struct. You can access the
+    /// [`WebAssembly.Memory`](https://developer.mozilla.org/en-US/docs/WebAssembly/JavaScript_interface/Memory)
+    /// instance by using {@linkcode wasmMemory}.
+    #[wasm_bindgen]
+    pub fn addr(&self) -> *const FFI_ArrowDeviceArrayStream {
+        self.1.as_ref() as *const _
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::ffi::from_ffi;
+    use arrow_array::{Array, Int32Array, RecordBatch, StructArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::test_util::three_batch_table;
+
+    #[test]
+    fn device_stream_round_trip() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )
+        .unwrap();
+
+        let stream: FFIArrowDeviceArrayStream =
+            FFIArrowArrayStream::from_batches(schema.clone(), vec![batch.clone()]).into();
+        let raw = stream.addr() as *mut FFI_ArrowDeviceArrayStream;
+
+        unsafe {
+            assert_eq!((*raw).device_type, ARROW_DEVICE_CPU);
+
+            let mut ffi_schema = FFI_ArrowSchema::empty();
+            assert_eq!((*raw).get_schema.unwrap()(raw, &mut ffi_schema), 0);
+            assert_eq!(Schema::try_from(&ffi_schema).unwrap(), *schema);
+
+            let mut out = std::mem::MaybeUninit::<FFI_ArrowDeviceArray>::uninit();
+            assert_eq!((*raw).get_next.unwrap()(raw, out.as_mut_ptr()), 0);
+            let device_array = out.assume_init();
+            assert_eq!(device_array.device_type, ARROW_DEVICE_CPU);
+            let data = from_ffi(device_array.array, &ffi_schema).unwrap();
+            assert_eq!(StructArray::from(data), StructArray::from(batch));
+
+            let mut out = std::mem::MaybeUninit::<FFI_ArrowDeviceArray>::uninit();
+            assert_eq!((*raw).get_next.unwrap()(raw, out.as_mut_ptr()), 0);
+            assert!(out.assume_init().array.is_released());
+        }
+    }
+
+    #[test]
+    fn released_device_stream_rejects_calls() {
+        let (schema, batches) = three_batch_table();
+        let stream: FFIArrowDeviceArrayStream =
+            FFIArrowArrayStream::from_batches(schema, batches).into();
+        let raw = stream.addr() as *mut FFI_ArrowDeviceArrayStream;
+
+        unsafe {
+            // A consumer may hold on to the callbacks after releasing the stream
+            let get_schema = (*raw).get_schema.unwrap();
+            let get_next = (*raw).get_next.unwrap();
+            let get_last_error = (*raw).get_last_error.unwrap();
+            (*raw).release.unwrap()(raw);
+            assert!((*raw).release.is_none());
+            assert!((*raw).private_data.is_null());
+
+            let mut ffi_schema = FFI_ArrowSchema::empty();
+            assert_eq!(get_schema(raw, &mut ffi_schema), einval());
+            let mut out = std::mem::MaybeUninit::<FFI_ArrowDeviceArray>::uninit();
+            assert_eq!(get_next(raw, out.as_mut_ptr()), einval());
+            assert!(get_last_error(raw).is_null());
+        }
+    }
+
+    #[test]
+    fn device_data_from_ffi_data() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let ffi_data = FFIData::try_from(&array as &dyn Array).unwrap();
+        let device_data = FFIDeviceData::from(ffi_data);
+        assert_eq!(device_data.device_type(), ARROW_DEVICE_CPU);
+        assert_eq!(device_data.array.array.len(), 3);
+    }
+}
@@ -0,0 +1,239 @@
+//! Import Arrow data from C Data Interface structs located in WebAssembly memory.
+//!
+//! The `import_*` functions in this module take ownership of the structs they are given,
+//! following the [release
+//! rules](https://arrow.apache.org/docs/format/CDataInterface.html#release-callback-semantics-for-consumers)
+//! of the C Data Interface: the struct at the given address is moved out and marked as released,
+//! and its release callback will be called once the imported data is dropped.
+//!
+//! The `read_*` functions only borrow the struct they are given and copy its contents.
+
+use std::sync::Arc;
+
+use arrow_array::ffi::{self, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow_array::{make_array, Array, ArrayRef, RecordBatch, RecordBatchOptions, StructArray};
+use arrow_schema::{ArrowError, Field, Schema, SchemaRef};
+
+use crate::error::Result;
+use crate::validation::ValidationLevel;
+
+fn check_not_null<T>(ptr: *const T, name: &str) -> Result<()> {
+    if ptr.is_null() {
+        return Err(ArrowError::CDataInterface(format!("{name} pointer is null")).into());
+    }
+    Ok(())
+}
+
+/// Check that an `ArrowSchema` has not been moved out or released by arrow-rs, which would
+/// otherwise cause a panic when reading its format string.
+unsafe fn check_schema_readable(schema: *const FFI_ArrowSchema) -> Result<()> {
+    check_not_null(schema, "ArrowSchema")?;
+    // `format` is the first member of the C struct, and is null once arrow-rs has moved the struct
+    let format = unsafe { *(schema as *const *const std::os::raw::c_char) };
+    if format.is_null() {
+        return Err(ArrowError::CDataInterface(
+            "ArrowSchema has already been released".to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Read a schema from an `ArrowSchema` describing a struct, without taking ownership of it.
+///
+/// Schema-level metadata is read from the struct's metadata.
+///
+/// # Safety
+///
+/// `schema` must point to a valid C Data Interface struct.
+pub unsafe fn read_schema(schema: *const FFI_ArrowSchema) -> Result<Schema> {
+    unsafe { check_schema_readable(schema) }?;
+    Ok(Schema::try_from(unsafe { &*schema })?)
+}
+
+/// Read a field from an `ArrowSchema`, without taking ownership of it.
+///
+/// # Safety
+///
+/// `schema` must point to a valid C Data Interface struct.
+pub unsafe fn read_field(schema: *const FFI_ArrowSchema) -> Result<Field> {
+    unsafe { check_schema_readable(schema) }?;
+    Ok(Field::try_from(unsafe { &*schema })?)
+}
+
+/// Import an array and its field from an `ArrowArray` and `ArrowSchema` pair.
+///
+/// The imported data is validated at the given level before being returned.
+///
+/// # Safety
+///
+/// `array` and `schema` must point to valid C Data Interface structs.
+pub unsafe fn import_array(
+    array: *mut FFI_ArrowArray,
+    schema: *mut FFI_ArrowSchema,
+    validation: ValidationLevel,
+) -> Result<(ArrayRef, Field)> {
+    check_not_null(array, "ArrowArray")?;
+    unsafe { check_schema_readable(schema) }?;
+
+    let ffi_array = unsafe { FFI_ArrowArray::from_raw(array) };
+    let ffi_schema = unsafe { FFI_ArrowSchema::from_raw(schema) };
+
+    let field = Field::try_from(&ffi_schema)?;
+    let data = unsafe { ffi::from_ffi(ffi_array, &ffi_schema) }?;
+    validation.validate(&data)?;
+    Ok((make_array(data), field))
+}
+
+/// Import a record batch from an `ArrowArray` and `ArrowSchema` pair describing a struct array.
+///
+/// Schema-level metadata is read from the `ArrowSchema`.
+///
+/// # Safety
+///
+/// `array` and `schema` must point to valid C Data Interface structs.
+pub unsafe fn import_record_batch(
+    array: *mut FFI_ArrowArray,
+    schema: *mut FFI_ArrowSchema,
+    validation: ValidationLevel,
+) -> Result<RecordBatch> {
+    let arrow_schema = unsafe { read_schema(schema) }?;
+
+    let (array, _field) = unsafe { import_array(array, schema, validation) }?;
+    let struct_array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| {
+            ArrowError::CDataInterface("Expected a struct array for a RecordBatch".to_string())
+        })?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(struct_array.len()));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(arrow_schema),
+        struct_array.columns().to_vec(),
+        &options,
+    )?)
+}
+
+/// Import a schema and all record batches from an `ArrowArrayStream`.
+///
+/// The stream is fully consumed and released before this function returns. Each batch is
+/// validated at the given level as it is pulled from the stream.
+///
+/// # Safety
+///
+/// `stream` must point to a valid C Stream Interface struct.
+pub unsafe fn import_stream(
+    stream: *mut FFI_ArrowArrayStream,
+    validation: ValidationLevel,
+) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    check_not_null(stream, "ArrowArrayStream")?;
+    let reader = unsafe { ArrowArrayStreamReader::from_raw(stream) }?;
+    let schema = arrow_array::RecordBatchReader::schema(&reader);
+    // The reader rebuilds each batch from a struct array, which drops schema-level metadata
+    let batches = reader
+        .map(|batch| {
+            let batch = batch?.with_schema(schema.clone())?;
+            validation.validate_batch(&batch)?;
+            Ok(batch)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((schema, batches))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::DataType;
+
+    use super::*;
+    use crate::ffi::{FFIArrowArrayStream, FFIData, FFISchema};
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ])
+        .with_metadata([("key".to_string(), "value".to_string())].into());
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn import_array_round_trip() {
+        let field = Field::new("x", DataType::Int32, true)
+            .with_metadata([("ARROW:extension:name".to_string(), "ext".to_string())].into());
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let ffi_data = FFIData::from_arrow(&array, &field).unwrap();
+
+        let (imported, imported_field) = unsafe {
+            import_array(
+                ffi_data.array_addr() as *mut _,
+                ffi_data.schema_addr() as *mut _,
+                ValidationLevel::Full,
+            )
+        }
+        .unwrap();
+        assert_eq!(imported.as_ref(), &array as &dyn Array);
+        assert_eq!(imported_field, field);
+    }
+
+    #[test]
+    fn import_record_batch_round_trip() {
+        let batch = batch();
+        let ffi_data = FFIData::try_from(&batch).unwrap();
+        let imported = unsafe {
+            import_record_batch(
+                ffi_data.array_addr() as *mut _,
+                ffi_data.schema_addr() as *mut _,
+                ValidationLevel::Basic,
+            )
+        }
+        .unwrap();
+        assert_eq!(imported, batch);
+    }
+
+    #[test]
+    fn import_stream_round_trip() {
+        let batch = batch();
+        let stream = FFIArrowArrayStream::from_batches(batch.schema(), vec![batch.clone(); 2]);
+        let (schema, batches) =
+            unsafe { import_stream(stream.addr() as *mut _, ValidationLevel::Full) }.unwrap();
+        assert_eq!(schema, batch.schema());
+        assert_eq!(batches, vec![batch.clone(), batch]);
+    }
+
+    #[test]
+    fn read_schema_does_not_take_ownership() {
+        let batch = batch();
+        let ffi_schema = FFISchema::try_from(batch.schema_ref().as_ref()).unwrap();
+        let schema = unsafe { read_schema(ffi_schema.addr()) }.unwrap();
+        assert_eq!(&schema, batch.schema_ref().as_ref());
+
+        // The struct can still be read after the first read
+        let field = unsafe { read_field(ffi_schema.addr()) }.unwrap();
+        assert_eq!(
+            field.data_type(),
+            &DataType::Struct(schema.fields().clone())
+        );
+    }
+
+    #[test]
+    fn read_released_schema() {
+        let ffi_schema = FFI_ArrowSchema::empty();
+        assert!(unsafe { read_schema(&ffi_schema) }.is_err());
+    }
+
+    #[test]
+    fn import_null_pointer() {
+        let result = unsafe { import_stream(std::ptr::null_mut(), ValidationLevel::Full) };
+        assert!(result.is_err());
+    }
+}
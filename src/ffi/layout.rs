@@ -0,0 +1,164 @@
+use arrow_buffer::Buffer;
+use arrow_data::ArrayData;
+use arrow_schema::DataType;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::WasmResult;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_BufferLayout: &'static str = r#"
+export type BufferRegion = {
+  ptr: number;
+  byteLength: number;
+};
+
+export type BufferLayout = {
+  dataType: string;
+  length: number;
+  offset: number;
+  nullCount: number;
+  nullBitmap: BufferRegion | null;
+  nullOffset: number;
+  buffers: BufferRegion[];
+  children: BufferLayout[];
+  dictionary: BufferLayout | null;
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "BufferLayout")]
+    pub type BufferLayout;
+}
+
+/// A contiguous region of WebAssembly memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferRegion {
+    /// The address of the first byte of the region in WebAssembly memory.
+    pub ptr: usize,
+    /// The number of bytes in the region.
+    pub byte_length: usize,
+}
+
+impl From<&Buffer> for BufferRegion {
+    fn from(value: &Buffer) -> Self {
+        Self {
+            ptr: value.as_ptr() as usize,
+            byte_length: value.len(),
+        }
+    }
+}
+
+/// A description of the physical layout of an Arrow array in WebAssembly memory.
+///
+/// This mirrors the buffers of the Arrow [columnar
+/// format](https://arrow.apache.org/docs/format/Columnar.html): `buffers` follows the buffer order
+/// of the data type (excluding the validity bitmap, which is stored in `null_bitmap`), and
+/// element `i` of the array is element `offset + i` of those buffers. The validity of element `i`
+/// is bit `null_offset + i` of `null_bitmap`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrayLayout {
+    pub data_type: String,
+    pub length: usize,
+    pub offset: usize,
+    pub null_count: usize,
+    pub null_bitmap: Option<BufferRegion>,
+    pub null_offset: usize,
+    pub buffers: Vec<BufferRegion>,
+    pub children: Vec<ArrayLayout>,
+    pub dictionary: Option<Box<ArrayLayout>>,
+}
+
+impl From<&ArrayData> for ArrayLayout {
+    fn from(value: &ArrayData) -> Self {
+        let (null_bitmap, null_offset) = match value.nulls() {
+            Some(nulls) => {
+                // Point at the byte holding the first validity bit, so that the region only
+                // covers the bits of this array
+                let bit_offset = nulls.offset();
+                let null_offset = bit_offset % 8;
+                let region = BufferRegion {
+                    ptr: nulls.buffer().as_ptr() as usize + bit_offset / 8,
+                    byte_length: (null_offset + nulls.len() + 7) / 8,
+                };
+                (Some(region), null_offset)
+            }
+            None => (None, 0),
+        };
+
+        // arrow-rs stores dictionary values as the only child of a dictionary array, whereas the
+        // Arrow format describes them separately from the children
+        let (children, dictionary) = match value.data_type() {
+            DataType::Dictionary(_, _) => {
+                (vec![], Some(Box::new(Self::from(&value.child_data()[0]))))
+            }
+            _ => (value.child_data().iter().map(Self::from).collect(), None),
+        };
+
+        Self {
+            data_type: value.data_type().to_string(),
+            length: value.len(),
+            offset: value.offset(),
+            null_count: value.null_count(),
+            null_bitmap,
+            null_offset,
+            buffers: value.buffers().iter().map(BufferRegion::from).collect(),
+            children,
+            dictionary,
+        }
+    }
+}
+
+impl ArrayLayout {
+    /// Convert this layout to a plain JS object.
+    pub fn to_js(&self) -> WasmResult<BufferLayout> {
+        Ok(serde_wasm_bindgen::to_value(self)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::types::Int8Type;
+    use arrow_array::{Array, DictionaryArray, Int32Array, StringArray};
+
+    use super::*;
+
+    #[test]
+    fn layout_of_sliced_string_array() {
+        let array = StringArray::from(vec![Some("a"), None, Some("bc"), Some("def")]).slice(1, 3);
+        let layout = ArrayLayout::from(&array.to_data());
+
+        // Slicing a string array slices its offsets buffer rather than setting an offset
+        assert_eq!(layout.length, 3);
+        assert_eq!(layout.offset, 0);
+        assert_eq!(layout.null_count, 1);
+        assert_eq!(layout.null_offset, 1);
+        assert_eq!(layout.null_bitmap.unwrap().byte_length, 1);
+        // Offsets and values buffers
+        assert_eq!(layout.buffers.len(), 2);
+        assert_eq!(
+            layout.buffers[0].byte_length,
+            4 * std::mem::size_of::<i32>()
+        );
+        assert_eq!(layout.buffers[1].byte_length, 6);
+    }
+
+    #[test]
+    fn layout_of_dictionary_array() {
+        let array: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let layout = ArrayLayout::from(&array.to_data());
+
+        assert!(layout.children.is_empty());
+        assert_eq!(layout.buffers.len(), 1);
+        let dictionary = layout.dictionary.unwrap();
+        assert_eq!(dictionary.length, 2);
+        assert_eq!(dictionary.data_type, "Utf8");
+
+        let primitive = ArrayLayout::from(&Int32Array::from(vec![1, 2]).to_data());
+        assert!(primitive.null_bitmap.is_none());
+        assert_eq!(primitive.buffers[0].byte_length, 8);
+    }
+}
@@ -1,7 +1,17 @@
 pub mod chunked;
 pub mod data;
+pub mod device;
+pub mod import;
+pub mod layout;
+pub mod reader;
+pub mod registry;
 pub mod schema;
+pub mod stream;
 
 pub use chunked::FFIStream;
 pub use data::FFIData;
+pub use device::{FFIArrowDeviceArrayStream, FFIDeviceData};
+pub use reader::FFIRecordBatchReader;
+pub use registry::{live_ffi_exports, release_all_ffi_exports};
 pub use schema::FFISchema;
+pub use stream::FFIArrowArrayStream;
@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+
+use arrow_array::ffi;
+use arrow_array::{Array, RecordBatchIterator, RecordBatchReader, StructArray};
+use wasm_bindgen::prelude::*;
+
+use crate::error::{Result, WasmResult};
+use crate::ffi::registry::{register, FFIExportKind, Registration};
+
+/// A lazily-exported stream of record batches in WebAssembly memory exposed as FFI-compatible
+/// structs through the Arrow C Data Interface.
+///
+/// Unlike {@linkcode FFIStream}, which converts every batch up front, this only holds the
+/// `ArrowArray` struct for the batch the consumer is currently reading. Calling {@linkcode
+/// FFIRecordBatchReader.next} releases the current batch before building the next one, so peak
+/// memory stays close to the size of a single batch when the reader owns its batches.
+///
+/// **Example**:
+///
+/// ```ts
+/// import * as arrow from "apache-arrow";
+/// import { parseRecordBatch } from "arrow-js-ffi";
+///
+/// const reader: FFIRecordBatchReader = wasmTable.intoFFIReader();
+/// const wasmMemory: WebAssembly.Memory = wasmMemory();
+///
+/// const jsBatches: arrow.RecordBatch[] = [];
+/// while (reader.next()) {
+///   // Copy the batch, as its memory is released on the next call to `next`.
+///   const jsRecordBatch = parseRecordBatch(
+///     wasmMemory.buffer,
+///     reader.arrayAddr(),
+///     reader.schemaAddr(),
+///     true
+///   );
+///   jsBatches.push(jsRecordBatch);
+/// }
+/// reader.free();
+/// ```
+///
+/// ## Memory management
+///
+/// Note that this reader will not be released automatically. You need to manually call `.free()`
+/// to release memory.
+#[wasm_bindgen]
+pub struct FFIRecordBatchReader {
+    registration: Registration,
+    schema: Box<ffi::FFI_ArrowSchema>,
+    reader: Box<Option<Box<dyn RecordBatchReader>>>,
+    current: Box<ffi::FFI_ArrowArray>,
+    /// The sizes of the current batch, if any, and of every batch not yet read.
+    byte_sizes: VecDeque<usize>,
+}
+
+impl FFIRecordBatchReader {
+    /// Construct an [FFIRecordBatchReader] from a schema and a sequence of record batches.
+    ///
+    /// Each batch is dropped by the reader once the consumer has moved past it, and its size is
+    /// then deducted from the byte size recorded in the export registry.
+    pub fn from_batches(
+        schema: arrow_schema::SchemaRef,
+        batches: Vec<arrow_array::RecordBatch>,
+    ) -> Result<Self> {
+        let byte_sizes = batches
+            .iter()
+            .map(|batch| batch.get_array_memory_size())
+            .collect::<VecDeque<_>>();
+        let reader: Box<dyn RecordBatchReader> = Box::new(RecordBatchIterator::new(
+            batches.into_iter().map(Ok),
+            schema,
+        ));
+
+        let mut schema = Box::new(ffi::FFI_ArrowSchema::try_from(reader.schema().as_ref())?);
+        let mut reader = Box::new(Some(reader));
+        let mut current = Box::new(ffi::FFI_ArrowArray::empty());
+
+        let schema_ptr = schema.as_mut() as *mut ffi::FFI_ArrowSchema;
+        let reader_ptr = reader.as_mut() as *mut Option<Box<dyn RecordBatchReader>>;
+        let current_ptr = current.as_mut() as *mut ffi::FFI_ArrowArray;
+        let registration = register(
+            FFIExportKind::RecordBatchReader,
+            byte_sizes.iter().sum(),
+            move || unsafe {
+                drop(ffi::FFI_ArrowArray::from_raw(current_ptr));
+                drop(ffi::FFI_ArrowSchema::from_raw(schema_ptr));
+                drop((*reader_ptr).take());
+            },
+        );
+
+        Ok(Self {
+            registration,
+            schema,
+            reader,
+            current,
+            byte_sizes,
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl FFIRecordBatchReader {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.registration.byte_size()
+    }
+
+    /// Get the pointer to the ArrowSchema FFI struct
+    #[wasm_bindgen(js_name = schemaAddr)]
+    pub fn schema_addr(&self) -> *const ffi::FFI_ArrowSchema {
+        self.schema.as_ref() as *const _
+    }
+
+    /// Advance to the next batch of the stream.
+    ///
+    /// This releases the `ArrowArray` struct of the current batch, so any views created over its
+    /// memory must no longer be used.
+    ///
+    /// @returns `true` if a new batch is available from {@linkcode FFIRecordBatchReader.arrayAddr},
+    /// or `false` once the stream is exhausted.
+    #[wasm_bindgen(js_name = next)]
+    pub fn next_batch(&mut self) -> WasmResult<bool> {
+        // Release the previous batch before decoding the next one
+        if !self.current.is_released() {
+            self.byte_sizes.pop_front();
+        }
+        *self.current = ffi::FFI_ArrowArray::empty();
+        self.registration
+            .set_byte_size(self.byte_sizes.iter().sum());
+
+        let reader = match self.reader.as_mut() {
+            Some(reader) => reader,
+            None => return Ok(false),
+        };
+        match reader.next() {
+            Some(batch) => {
+                let data = StructArray::from(batch?).into_data();
+                *self.current = ffi::FFI_ArrowArray::new(&data);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Get the pointer to the ArrowArray FFI struct of the current batch.
+    ///
+    /// This is only valid after {@linkcode FFIRecordBatchReader.next} has returned `true`.
+    ///
+    /// @returns number pointer to an ArrowArray FFI struct in Wasm memory
+    #[wasm_bindgen(js_name = arrayAddr)]
+    pub fn array_addr(&self) -> *const ffi::FFI_ArrowArray {
+        self.current.as_ref() as *const _
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch};
+    use arrow_buffer::{Buffer, ScalarBuffer};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+
+    #[test]
+    fn reader_drops_consumed_batches() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let buffers = (0..3)
+            .map(|i| Buffer::from_vec(vec![i; 4]))
+            .collect::<Vec<_>>();
+        // Only the batches given to the reader share the buffers with the test
+        let batches = buffers
+            .iter()
+            .map(|buffer| {
+                let array = Int32Array::new(ScalarBuffer::from(buffer.clone()), None);
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let is_held = |i: usize| buffers[i].strong_count() > 1;
+
+        let batch_size = batches[0].get_array_memory_size();
+        let mut reader = FFIRecordBatchReader::from_batches(schema, batches).unwrap();
+        assert!((0..3).all(is_held));
+        assert_eq!(reader.byte_size(), 3 * batch_size);
+        for i in 0..3 {
+            assert!(reader.next_batch().unwrap());
+            assert_eq!(reader.current.len(), 4);
+            // Earlier batches are dropped, while the current and later batches are still held
+            assert!((0..i).all(|j| !is_held(j)));
+            assert!((i..3).all(is_held));
+            assert_eq!(reader.byte_size(), (3 - i) * batch_size);
+        }
+        assert!(!reader.next_batch().unwrap());
+        assert!(reader.current.is_released());
+        assert!((0..3).all(|j| !is_held(j)));
+        assert_eq!(reader.byte_size(), 0);
+    }
+}
@@ -0,0 +1,253 @@
+//! A registry of live FFI exports.
+//!
+//! Every FFI object handed out to JS pins Arrow buffers in WebAssembly memory until its `.free()`
+//! method is called. Each of these objects registers itself here on creation and unregisters
+//! itself when dropped, so that leaked exports can be found with {@linkcode liveFFIExports} and
+//! released with {@linkcode releaseAllFFIExports}.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::WasmResult;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_FFIExports: &'static str = r#"
+export type FFIExportKind =
+  | "data"
+  | "schema"
+  | "stream"
+  | "arrayStream"
+  | "deviceData"
+  | "deviceArrayStream"
+  | "recordBatchReader";
+
+export type FFIExportInfo = {
+  id: number;
+  kind: FFIExportKind;
+  byteSize: number;
+};
+
+export type FFIExports = {
+  count: number;
+  byteSize: number;
+  exports: FFIExportInfo[];
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "FFIExports")]
+    pub type FFIExports;
+}
+
+/// The kind of FFI object that was exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FFIExportKind {
+    Data,
+    Schema,
+    Stream,
+    ArrayStream,
+    DeviceData,
+    DeviceArrayStream,
+    RecordBatchReader,
+}
+
+/// Information about a single live FFI export.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FFIExportInfo {
+    pub id: usize,
+    pub kind: FFIExportKind,
+    pub byte_size: usize,
+}
+
+/// A summary of all live FFI exports.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FFIExportSummary {
+    pub count: usize,
+    pub byte_size: usize,
+    pub exports: Vec<FFIExportInfo>,
+}
+
+struct Entry {
+    kind: FFIExportKind,
+    byte_size: usize,
+    release: Box<dyn FnOnce()>,
+}
+
+#[derive(Default)]
+struct Registry {
+    next_id: usize,
+    entries: BTreeMap<usize, Entry>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+/// A handle to an entry in the export registry.
+///
+/// The entry is removed from the registry when this handle is dropped, so it must be dropped no
+/// later than the FFI structs that its release callback refers to.
+#[derive(Debug)]
+pub(crate) struct Registration(usize);
+
+impl Registration {
+    /// The pinned byte size recorded for this export, or 0 if it has already been released.
+    pub(crate) fn byte_size(&self) -> usize {
+        REGISTRY.with(|registry| {
+            registry
+                .borrow()
+                .entries
+                .get(&self.0)
+                .map_or(0, |entry| entry.byte_size)
+        })
+    }
+
+    /// Update the pinned byte size recorded for this export, if it has not been released yet.
+    pub(crate) fn set_byte_size(&self, byte_size: usize) {
+        REGISTRY.with(|registry| {
+            if let Some(entry) = registry.borrow_mut().entries.get_mut(&self.0) {
+                entry.byte_size = byte_size;
+            }
+        })
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        // The registry may already be destroyed if this is dropped during thread teardown
+        let _ = REGISTRY.try_with(|registry| registry.borrow_mut().entries.remove(&self.0));
+    }
+}
+
+/// Register a new FFI export.
+///
+/// `release` is called by [release_all] and must release every FFI struct owned by the export,
+/// leaving them in a released (but still valid) state.
+pub(crate) fn register(
+    kind: FFIExportKind,
+    byte_size: usize,
+    release: impl FnOnce() + 'static,
+) -> Registration {
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        let id = registry.next_id;
+        registry.next_id += 1;
+        registry.entries.insert(
+            id,
+            Entry {
+                kind,
+                byte_size,
+                release: Box::new(release),
+            },
+        );
+        Registration(id)
+    })
+}
+
+/// Summarize all FFI exports that have not yet been freed or released.
+pub fn live_exports() -> FFIExportSummary {
+    REGISTRY.with(|registry| {
+        let registry = registry.borrow();
+        let exports = registry
+            .entries
+            .iter()
+            .map(|(id, entry)| FFIExportInfo {
+                id: *id,
+                kind: entry.kind,
+                byte_size: entry.byte_size,
+            })
+            .collect::<Vec<_>>();
+        FFIExportSummary {
+            count: exports.len(),
+            byte_size: exports.iter().map(|export| export.byte_size).sum(),
+            exports,
+        }
+    })
+}
+
+/// Release the FFI structs of every live export, returning the number of exports released.
+///
+/// The exported objects themselves stay valid, but all of their FFI structs are marked as
+/// released.
+pub fn release_all() -> usize {
+    // Take the entries out before releasing them, as releasing may drop other registrations
+    let entries = REGISTRY.with(|registry| std::mem::take(&mut registry.borrow_mut().entries));
+    let count = entries.len();
+    for entry in entries.into_values() {
+        (entry.release)();
+    }
+    count
+}
+
+/// Returns a summary of all FFI exports that are still alive, i.e. that have not yet been freed
+/// with `.free()` or released with {@linkcode releaseAllFFIExports}.
+///
+/// Each export reports the number of bytes of Arrow memory it pins, as computed by
+/// `getArrayMemorySize`. Exports that do not pin any buffers, such as schemas, report 0.
+#[wasm_bindgen(js_name = liveFFIExports)]
+pub fn live_ffi_exports() -> WasmResult<FFIExports> {
+    Ok(serde_wasm_bindgen::to_value(&live_exports())?.into())
+}
+
+/// Release the FFI structs of every live export, so that the Arrow memory they pin can be
+/// reclaimed.
+///
+/// This is intended for teardown in tests and long-running pages. Any pointer previously handed
+/// out by an FFI object refers to a released struct after this call. You still need to call
+/// `.free()` on the FFI objects themselves.
+///
+/// @returns the number of exports that were released.
+#[wasm_bindgen(js_name = releaseAllFFIExports)]
+pub fn release_all_ffi_exports() -> usize {
+    release_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, Int32Array};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::ffi::{FFIData, FFISchema};
+
+    #[test]
+    fn registry_tracks_live_exports() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let ffi_data = FFIData::try_from(&array as &dyn Array).unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let ffi_schema = FFISchema::try_from(schema.as_ref()).unwrap();
+
+        let summary = live_exports();
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.exports[0].kind, FFIExportKind::Data);
+        assert_eq!(summary.exports[0].byte_size, array.get_array_memory_size());
+        assert_eq!(summary.exports[1].kind, FFIExportKind::Schema);
+        assert_eq!(summary.byte_size, array.get_array_memory_size());
+
+        drop(ffi_schema);
+        assert_eq!(live_exports().count, 1);
+        drop(ffi_data);
+        assert_eq!(live_exports().count, 0);
+    }
+
+    #[test]
+    fn release_all_releases_structs() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let ffi_data = FFIData::try_from(&array as &dyn Array).unwrap();
+        assert!(!ffi_data.array.is_released());
+
+        assert_eq!(release_all(), 1);
+        assert!(ffi_data.array.is_released());
+        assert_eq!(ffi_data.byte_size(), 0);
+        assert_eq!(live_exports().count, 0);
+    }
+}
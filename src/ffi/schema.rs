@@ -3,13 +3,24 @@ use arrow_schema::ArrowError;
 use wasm_bindgen::prelude::*;
 
 use crate::error::Result;
+use crate::ffi::registry::{register, FFIExportKind, Registration};
 
 #[wasm_bindgen]
-pub struct FFISchema(Box<ffi::FFI_ArrowSchema>);
+pub struct FFISchema {
+    _registration: Registration,
+    schema: Box<ffi::FFI_ArrowSchema>,
+}
 
 impl FFISchema {
-    pub fn new(schema: Box<ffi::FFI_ArrowSchema>) -> Self {
-        Self(schema)
+    pub fn new(mut schema: Box<ffi::FFI_ArrowSchema>) -> Self {
+        let schema_ptr = schema.as_mut() as *mut ffi::FFI_ArrowSchema;
+        let registration = register(FFIExportKind::Schema, 0, move || unsafe {
+            drop(ffi::FFI_ArrowSchema::from_raw(schema_ptr));
+        });
+        Self {
+            _registration: registration,
+            schema,
+        }
     }
 
     /// Construct an [FFIData] from an Arrow array and optionally a field.
@@ -53,7 +64,7 @@ impl FFISchema {
     /// ```
     #[wasm_bindgen]
     pub fn addr(&self) -> *const ffi::FFI_ArrowSchema {
-        self.0.as_ref() as *const _
+        self.schema.as_ref() as *const _
     }
 }
 
@@ -93,12 +104,12 @@ impl TryFrom<&crate::Field> for FFISchema {
 
 impl From<Box<ffi::FFI_ArrowSchema>> for FFISchema {
     fn from(value: Box<ffi::FFI_ArrowSchema>) -> Self {
-        Self(value)
+        Self::new(value)
     }
 }
 
 impl From<ffi::FFI_ArrowSchema> for FFISchema {
     fn from(value: ffi::FFI_ArrowSchema) -> Self {
-        Self(Box::new(value))
+        Self::new(Box::new(value))
     }
 }
@@ -1,64 +1,123 @@
-use std::sync::Arc;
-
-use arrow::array::Array;
-use arrow::ffi;
-use arrow::ffi_stream;
+use arrow_array::ffi_stream;
+use arrow_array::{RecordBatchIterator, RecordBatchReader};
 use wasm_bindgen::prelude::*;
 
-use crate::error::{Result, WasmResult};
-use crate::{Field, Schema};
-
+use crate::ffi::registry::{register, FFIExportKind, Registration};
 
+/// An Arrow C Stream in WebAssembly memory conforming to the [Arrow C Stream
+/// Interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+///
+/// Unlike {@linkcode FFIStream}, this is a real `ArrowArrayStream` struct. Any consumer of the C
+/// Stream Interface can pull batches from it by calling the `get_schema`, `get_next`,
+/// `get_last_error` and `release` callbacks stored in the struct. These callbacks live in this
+/// instance's function table, which you can access through {@linkcode _functionTable}.
+///
+/// A consumer may move the stream out of this struct (as the C Stream Interface allows). In that
+/// case the consumer becomes responsible for calling `release`.
+///
+/// ## Memory management
+///
+/// Note that this stream will not be released automatically. You need to manually call `.free()`
+/// to release memory.
 #[wasm_bindgen]
-pub struct FFIArrowArrayStream(Box<ffi_stream::FFI_ArrowArrayStream>);
+pub struct FFIArrowArrayStream(Registration, Box<ffi_stream::FFI_ArrowArrayStream>);
 
 impl FFIArrowArrayStream {
-    pub fn new(stream: Box<ffi_stream::FFI_ArrowArrayStream>) -> Self {
-        Self(stream)
+    pub(crate) fn new(mut stream: Box<ffi_stream::FFI_ArrowArrayStream>, byte_size: usize) -> Self {
+        let stream_ptr = stream.as_mut() as *mut ffi_stream::FFI_ArrowArrayStream;
+        let registration = register(FFIExportKind::ArrayStream, byte_size, move || unsafe {
+            drop(ffi_stream::FFI_ArrowArrayStream::from_raw(stream_ptr));
+        });
+        Self(registration, stream)
+    }
+
+    /// Construct an [FFIArrowArrayStream] that lazily pulls batches from a
+    /// [RecordBatchReader].
+    ///
+    /// The batches have not been produced yet, so the stream is registered as pinning no memory.
+    pub fn from_reader(reader: Box<dyn RecordBatchReader + Send>) -> Self {
+        let stream = ffi_stream::FFI_ArrowArrayStream::new(reader);
+        Self::new(Box::new(stream), 0)
+    }
+
+    /// Construct an [FFIArrowArrayStream] from a schema and a sequence of record batches.
+    pub fn from_batches(
+        schema: arrow_schema::SchemaRef,
+        batches: Vec<arrow_array::RecordBatch>,
+    ) -> Self {
+        let byte_size = batches
+            .iter()
+            .map(|batch| batch.get_array_memory_size())
+            .sum();
+        let reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+        let stream = ffi_stream::FFI_ArrowArrayStream::new(Box::new(reader));
+        Self::new(Box::new(stream), byte_size)
+    }
+
+    /// Consume this and return the underlying `FFI_ArrowArrayStream`
+    pub fn into_inner(self) -> ffi_stream::FFI_ArrowArrayStream {
+        *self.1
     }
 }
 
 #[wasm_bindgen]
 impl FFIArrowArrayStream {
+    /// The number of bytes of Arrow memory pinned by this export.
+    #[wasm_bindgen(getter, js_name = byteSize)]
+    pub fn byte_size(&self) -> usize {
+        self.0.byte_size()
+    }
+
+    /// Access the pointer to the
+    /// [`ArrowArrayStream`](https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definition)
+    /// struct. You can access the
+    /// [`WebAssembly.Memory`](https://developer.mozilla.org/en-US/docs/WebAssembly/JavaScript_interface/Memory)
+    /// instance by using {@linkcode wasmMemory}.
     #[wasm_bindgen]
     pub fn addr(&self) -> *const ffi_stream::FFI_ArrowArrayStream {
-        self.0.as_ref() as *const _
+        self.1.as_ref() as *const _
     }
 
-    #[wasm_bindgen]
-    pub fn get_schema(&mut self) -> WasmResult<FFIArrowSchema> {
-        let mut schema = ffi::FFI_ArrowSchema::empty();
-
-        let stream_ptr = self.0.as_mut() as *mut ffi_stream::FFI_ArrowArrayStream;
-        let ret_code = unsafe { (*stream_ptr).get_schema.unwrap()(stream_ptr, &mut schema) };
-
-        if ret_code == 0 {
-            Ok(schema.into())
-        } else {
-            Err(JsError::new(
-                "Cannot get schema from input stream. Error code: {ret_code:?}",
-            ))
-        }
+    /// Convert this to an `ArrowDeviceArrayStream` according to the Arrow C Device Data
+    /// Interface.
+    ///
+    /// This consumes the FFIArrowArrayStream, so any address previously returned by {@linkcode
+    /// FFIArrowArrayStream.addr} is no longer valid.
+    #[wasm_bindgen(js_name = intoDevice)]
+    pub fn into_device(self) -> crate::ffi::FFIArrowDeviceArrayStream {
+        self.into()
     }
+}
 
-    #[wasm_bindgen]
-    pub fn get_next(&mut self) -> WasmResult<Option<FFIArrowArray>> {
-        let mut array = ffi::FFI_ArrowArray::empty();
-
-        let stream_ptr = self.0.as_mut() as *mut ffi_stream::FFI_ArrowArrayStream;
-        let ret_code = unsafe { (*stream_ptr).get_next.unwrap()(stream_ptr, &mut array) };
-
-        if ret_code == 0 {
-            // The end of stream has been reached
-            if array.is_released() {
-                return Ok(None);
-            }
-
-            Ok(Some(array.into()))
-        } else {
-            Err(JsError::new(
-                "Cannot get array from input stream. Error code: {ret_code:?}",
-            ))
-        }
+impl From<Box<ffi_stream::FFI_ArrowArrayStream>> for FFIArrowArrayStream {
+    fn from(value: Box<ffi_stream::FFI_ArrowArrayStream>) -> Self {
+        // The memory pinned by a stream from elsewhere is unknown
+        Self::new(value, 0)
+    }
+}
+
+impl From<ffi_stream::FFI_ArrowArrayStream> for FFIArrowArrayStream {
+    fn from(value: ffi_stream::FFI_ArrowArrayStream) -> Self {
+        Self::from(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::ffi_stream::ArrowArrayStreamReader;
+
+    use super::*;
+    use crate::test_util::three_batch_table;
+
+    #[test]
+    fn stream_round_trip() {
+        let (schema, batches) = three_batch_table();
+
+        let stream = FFIArrowArrayStream::from_batches(schema.clone(), batches.clone());
+        let reader = unsafe { ArrowArrayStreamReader::from_raw(stream.addr() as *mut _) }.unwrap();
+        assert_eq!(reader.schema(), schema);
+
+        let imported = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(imported, batches);
     }
 }
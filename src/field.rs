@@ -21,6 +21,20 @@ pub struct Field(pub(crate) arrow_schema::FieldRef);
 
 #[wasm_bindgen]
 impl Field {
+    /// Read a Field from an `ArrowSchema` struct located in this instance's WebAssembly memory,
+    /// according to the Arrow C Data Interface.
+    ///
+    /// This reads the name, data type (including children and dictionary), flags and metadata of
+    /// the struct. This **does not take ownership** of the struct: its contents are copied, and
+    /// the caller remains responsible for releasing it.
+    ///
+    /// @param addr number pointer to an ArrowSchema FFI struct in Wasm memory
+    #[wasm_bindgen(js_name = fromFFI)]
+    pub fn from_ffi(addr: usize) -> WasmResult<Field> {
+        let field = unsafe { crate::ffi::import::read_field(addr as *const _) }?;
+        Ok(field.into())
+    }
+
     /// Export this field to an `FFISchema`` object, which can be read with arrow-js-ffi.
     #[wasm_bindgen(js_name = toFFI)]
     pub fn to_ffi(&self) -> WasmResult<crate::ffi::FFISchema> {
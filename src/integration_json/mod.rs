@@ -0,0 +1,418 @@
+//! Reading and writing the JSON format used by the Arrow integration tests.
+//!
+//! This format is a verbose, human-readable rendering of a schema and its record batches that
+//! every Arrow implementation can produce and consume, which makes it suitable for golden files
+//! checked into version control. It is not intended for exchanging data efficiently; use Arrow IPC
+//! for that.
+//!
+//! See <https://arrow.apache.org/docs/format/Integration.html#json-test-data-format>.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use serde_json::{json, Value};
+
+use crate::error::Result;
+
+mod read;
+mod schema;
+mod write;
+
+/// Write a schema and its record batches as integration JSON.
+///
+/// Dictionaries must not change between batches, as the format can only describe a single
+/// dictionary per dictionary-encoded field.
+pub fn write_json<'a>(
+    schema: &Schema,
+    batches: impl IntoIterator<Item = &'a RecordBatch>,
+) -> Result<String> {
+    let mut writer = write::BatchWriter::default();
+    let batches = batches
+        .into_iter()
+        .map(|batch| writer.batch_to_json(batch))
+        .collect::<Result<Vec<_>>>()?;
+    let dictionaries = writer
+        .dictionaries
+        .into_iter()
+        .map(|(id, column)| json!({"id": id, "data": {"count": column["count"], "columns": [column]}}))
+        .collect::<Vec<_>>();
+
+    let mut json = json!({
+        "schema": schema::schema_to_json(schema)?,
+        "batches": batches,
+    });
+    if !dictionaries.is_empty() {
+        json["dictionaries"] = Value::Array(dictionaries);
+    }
+    serde_json::to_string_pretty(&json).map_err(|err| json_error(err).into())
+}
+
+/// Read integration JSON into a schema and a sequence of record batches.
+pub fn read_json(json: &str) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let json: Value = serde_json::from_str(json).map_err(json_error)?;
+    let schema_json = get(&json, "schema")?;
+    let schema = Arc::new(schema::schema_from_json(schema_json)?);
+
+    let mut reader = read::BatchReader::try_new(schema_json, schema.fields())?;
+    if let Some(Value::Array(dictionaries)) = json.get("dictionaries") {
+        for dictionary in dictionaries {
+            reader.read_dictionary(dictionary)?;
+        }
+    }
+    let batches = array(&json, "batches")?
+        .iter()
+        .map(|batch| reader.read_batch(&schema, schema_json, batch))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((schema, batches))
+}
+
+fn json_error(err: serde_json::Error) -> ArrowError {
+    ArrowError::JsonError(err.to_string())
+}
+
+/// Returns the value of a required key of a JSON object.
+fn get<'a>(json: &'a Value, key: &str) -> Result<&'a Value> {
+    json.get(key).ok_or_else(|| {
+        ArrowError::ParseError(format!("Missing {key:?} in integration JSON: {json}")).into()
+    })
+}
+
+fn array<'a>(json: &'a Value, key: &str) -> Result<&'a Vec<Value>> {
+    get(json, key)?
+        .as_array()
+        .ok_or_else(|| schema::parse_error(key, json))
+}
+
+fn string<'a>(json: &'a Value, key: &str) -> Result<&'a str> {
+    get(json, key)?
+        .as_str()
+        .ok_or_else(|| schema::parse_error(key, json))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use arrow_array::builder::{
+        FixedSizeListBuilder, Int32Builder, ListBuilder, MapBuilder, StringBuilder,
+    };
+    use arrow_array::types::{Int16Type, Int32Type, Int8Type};
+    use arrow_array::*;
+    use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano, ScalarBuffer};
+    use arrow_schema::{DataType, Field, UnionFields};
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        let dictionary: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+
+        let mut list = ListBuilder::new(Int32Builder::new());
+        list.append_value([Some(1), None]);
+        list.append_null();
+        list.append_value([]);
+        list.append_value([Some(4)]);
+
+        let mut fixed_size_list = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        for i in 0..4 {
+            fixed_size_list.values().append_slice(&[i, i * 2]);
+            fixed_size_list.append(i != 1);
+        }
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for i in 0..4 {
+            map.keys().append_value(format!("k{i}"));
+            map.values().append_option((i != 2).then_some(i));
+            map.append(i != 3).unwrap();
+        }
+
+        let union_fields = UnionFields::try_new(
+            [0, 5],
+            [
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        )
+        .unwrap();
+        let sparse = UnionArray::try_new(
+            union_fields.clone(),
+            ScalarBuffer::from(vec![0, 5, 5, 0]),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])),
+            ],
+        )
+        .unwrap();
+        let dense = UnionArray::try_new(
+            union_fields,
+            ScalarBuffer::from(vec![0, 5, 5, 0]),
+            Some(ScalarBuffer::from(vec![0, 0, 1, 1])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )
+        .unwrap();
+
+        let run_ends = Int16Array::from(vec![1, 3, 4]);
+        let run_values = StringArray::from(vec![Some("x"), None, Some("y")]);
+        let run_array = RunArray::<Int16Type>::try_new(&run_ends, &run_values).unwrap();
+
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("f", DataType::Float64, true)),
+                Arc::new(Float64Array::from(vec![
+                    Some(1.5),
+                    None,
+                    Some(-2.0),
+                    Some(0.0),
+                ])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Boolean, false)),
+                Arc::new(BooleanArray::from(vec![true, false, true, true])) as ArrayRef,
+            ),
+        ]);
+
+        let mut metadata = HashMap::new();
+        metadata.insert("key".to_string(), "value".to_string());
+
+        let batch = RecordBatch::try_from_iter([
+            ("null", Arc::new(NullArray::new(4)) as ArrayRef),
+            (
+                "int64",
+                Arc::new(Int64Array::from(vec![
+                    Some(i64::MAX),
+                    None,
+                    Some(-1),
+                    Some(0),
+                ])),
+            ),
+            (
+                "uint64",
+                Arc::new(UInt64Array::from(vec![u64::MAX, 0, 1, 2])),
+            ),
+            (
+                "float16",
+                Arc::new(Float16Array::from(vec![
+                    Some(half_from_f64(1.5)),
+                    None,
+                    Some(half_from_f64(-0.25)),
+                    Some(half_from_f64(0.0)),
+                ])),
+            ),
+            (
+                "timestamp",
+                Arc::new(
+                    TimestampMillisecondArray::from(vec![Some(1), None, Some(3), Some(4)])
+                        .with_timezone("UTC"),
+                ),
+            ),
+            ("date32", Arc::new(Date32Array::from(vec![1, 2, 3, 4]))),
+            (
+                "time64",
+                Arc::new(Time64MicrosecondArray::from(vec![1, 2, 3, 4])),
+            ),
+            (
+                "duration",
+                Arc::new(DurationSecondArray::from(vec![1, 2, 3, 4])),
+            ),
+            (
+                "interval_ym",
+                Arc::new(IntervalYearMonthArray::from(vec![1, 2, 3, 4])),
+            ),
+            (
+                "interval_dt",
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    IntervalDayTime::new(1, 2);
+                    4
+                ])),
+            ),
+            (
+                "interval_mdn",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNano::new(1, 2, i64::MAX);
+                    4
+                ])),
+            ),
+            (
+                "decimal128",
+                Arc::new(
+                    Decimal128Array::from(vec![Some(12345), None, Some(-1), Some(0)])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "decimal256",
+                Arc::new(
+                    Decimal256Array::from(vec![i256::MAX, i256::MINUS_ONE, i256::ZERO, i256::ONE])
+                        .with_precision_and_scale(76, 3)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "utf8",
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    Some(""),
+                    Some("dé"),
+                ])),
+            ),
+            (
+                "large_utf8",
+                Arc::new(LargeStringArray::from(vec!["a", "b", "c", "d"])),
+            ),
+            (
+                "binary",
+                Arc::new(BinaryArray::from(vec![
+                    Some(&b"\x00\xff"[..]),
+                    None,
+                    Some(b""),
+                    Some(b"a"),
+                ])),
+            ),
+            (
+                "large_binary",
+                Arc::new(LargeBinaryArray::from(vec![&b"ab"[..], b"", b"c", b"d"])),
+            ),
+            (
+                "fixed_size_binary",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                        vec![Some(b"ab"), None, Some(b"cd"), Some(b"ef")].into_iter(),
+                        2,
+                    )
+                    .unwrap(),
+                ),
+            ),
+            ("list", Arc::new(list.finish())),
+            ("fixed_size_list", Arc::new(fixed_size_list.finish())),
+            ("struct", Arc::new(structs)),
+            ("map", Arc::new(map.finish())),
+            ("sparse_union", Arc::new(sparse)),
+            ("dense_union", Arc::new(dense)),
+            ("dictionary", Arc::new(dictionary)),
+            ("run_end_encoded", Arc::new(run_array)),
+        ])
+        .unwrap();
+        let schema = Arc::new(batch.schema().as_ref().clone().with_metadata(metadata));
+        batch.with_schema(schema).unwrap()
+    }
+
+    fn half_from_f64(
+        value: f64,
+    ) -> <arrow_array::types::Float16Type as ArrowPrimitiveType>::Native {
+        <arrow_array::types::Float16Type as ArrowPrimitiveType>::Native::from_f64(value)
+    }
+
+    #[test]
+    fn round_trip() {
+        let batch = batch();
+        let batches = [batch.clone(), batch.slice(1, 3)];
+        let json = write_json(&batch.schema(), &batches).unwrap();
+        let (schema, read_batches) = read_json(&json).unwrap();
+        assert_eq!(schema, batch.schema());
+        assert_eq!(read_batches.len(), 2);
+        assert_eq!(read_batches[0], batches[0]);
+
+        // Sliced run-end encoded arrays can't be compared directly, so check their runs instead
+        let columns = (0..batch.num_columns() - 1).collect::<Vec<_>>();
+        assert_eq!(
+            read_batches[1].project(&columns).unwrap(),
+            batches[1].project(&columns).unwrap()
+        );
+        let run_array = read_batches[1]
+            .column_by_name("run_end_encoded")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<RunArray<Int16Type>>()
+            .unwrap();
+        assert_eq!(run_array.run_ends().values(), &[2, 3]);
+        assert_eq!(run_array.values().null_count(), 1);
+
+        // Writing the decoded batches again gives identical output
+        assert_eq!(write_json(&schema, &read_batches).unwrap(), json);
+    }
+
+    #[test]
+    fn read_fixture() {
+        let json = r#"{
+            "schema": {
+                "fields": [
+                    {
+                        "name": "ints",
+                        "nullable": true,
+                        "type": {"name": "int", "isSigned": true, "bitWidth": 64},
+                        "children": []
+                    },
+                    {
+                        "name": "dict",
+                        "nullable": true,
+                        "type": {"name": "utf8"},
+                        "dictionary": {
+                            "id": 7,
+                            "indexType": {"name": "int", "isSigned": true, "bitWidth": 32},
+                            "isOrdered": false
+                        },
+                        "children": []
+                    }
+                ]
+            },
+            "batches": [
+                {
+                    "count": 3,
+                    "columns": [
+                        {"name": "ints", "count": 3, "VALIDITY": [1, 0, 1], "DATA": ["1", "0", 3]},
+                        {"name": "dict", "count": 3, "VALIDITY": [1, 1, 1], "DATA": [1, 0, 1]}
+                    ]
+                }
+            ],
+            "dictionaries": [
+                {
+                    "id": 7,
+                    "data": {
+                        "count": 2,
+                        "columns": [
+                            {
+                                "name": "DICT7",
+                                "count": 2,
+                                "VALIDITY": [1, 1],
+                                "OFFSET": [0, 3, 6],
+                                "DATA": ["foo", "bar"]
+                            }
+                        ]
+                    }
+                }
+            ]
+        }"#;
+        let (schema, batches) = read_json(json).unwrap();
+        let expected_dictionary: DictionaryArray<Int32Type> =
+            vec!["bar", "foo", "bar"].into_iter().collect();
+        let expected = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(expected_dictionary),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batches, vec![expected]);
+    }
+
+    #[test]
+    fn dictionary_replacement() {
+        let batches = [vec!["a", "b"], vec!["c"]]
+            .into_iter()
+            .map(|values| {
+                let dictionary: DictionaryArray<Int32Type> = values.into_iter().collect();
+                RecordBatch::try_from_iter([("d", Arc::new(dictionary) as ArrayRef)]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(write_json(&batches[0].schema(), &batches).is_err());
+    }
+}
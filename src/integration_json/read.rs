@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use arrow_array::types::Float16Type;
+use arrow_array::{make_array, ArrayRef, ArrowPrimitiveType, RecordBatch};
+use arrow_buffer::{
+    i256, ArrowNativeType, BooleanBuffer, Buffer, IntervalDayTime, IntervalMonthDayNano, NullBuffer,
+};
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType, Fields, IntervalUnit, SchemaRef, UnionMode};
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::integration_json::schema::{child_fields, parse_error, unsupported};
+use crate::integration_json::{array, get};
+
+/// Converts JSON record batches to Arrow, resolving dictionary-encoded columns against the
+/// dictionaries read so far.
+pub(crate) struct BatchReader<'a> {
+    /// The JSON field and value type of each dictionary id in the schema.
+    dictionary_fields: HashMap<i64, (&'a Value, DataType)>,
+    dictionaries: HashMap<i64, ArrayData>,
+}
+
+impl<'a> BatchReader<'a> {
+    pub(crate) fn try_new(schema_json: &'a Value, fields: &Fields) -> Result<Self> {
+        let mut dictionary_fields = HashMap::new();
+        collect_dictionary_fields(
+            array(schema_json, "fields")?,
+            fields,
+            &mut dictionary_fields,
+        )?;
+        Ok(Self {
+            dictionary_fields,
+            dictionaries: HashMap::new(),
+        })
+    }
+
+    /// Read an entry of the `dictionaries` section.
+    pub(crate) fn read_dictionary(&mut self, json: &Value) -> Result<()> {
+        let id = integer::<i64>(get(json, "id")?)?;
+        let (field_json, value_type) = self
+            .dictionary_fields
+            .get(&id)
+            .ok_or_else(|| parse_error("id", json))?;
+        let data = get(json, "data")?;
+        let column = array(data, "columns")?
+            .first()
+            .ok_or_else(|| parse_error("columns", data))?;
+        let values = self.column_from_json(field_json, value_type, column)?;
+        self.dictionaries.insert(id, values);
+        Ok(())
+    }
+
+    pub(crate) fn read_batch(
+        &self,
+        schema: &SchemaRef,
+        schema_json: &Value,
+        json: &Value,
+    ) -> Result<RecordBatch> {
+        let field_jsons = array(schema_json, "fields")?;
+        let columns = array(json, "columns")?;
+        if columns.len() != schema.fields().len() {
+            return Err(parse_error("columns", json));
+        }
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(field_jsons)
+            .zip(columns)
+            .map(|((field, field_json), column)| {
+                let data = self.column_from_json(field_json, field.data_type(), column)?;
+                Ok(make_array(data))
+            })
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        Ok(RecordBatch::try_new(schema.clone(), columns)?)
+    }
+
+    fn column_from_json(
+        &self,
+        field_json: &Value,
+        data_type: &DataType,
+        column: &Value,
+    ) -> Result<ArrayData> {
+        let count = integer::<usize>(get(column, "count")?)?;
+        let mut builder = ArrayData::builder(data_type.clone()).len(count);
+        if let Some(validity) = column.get("VALIDITY") {
+            builder = builder.nulls(validity_from_json(validity)?);
+        }
+
+        let children = || -> Result<Vec<ArrayData>> {
+            let child_jsons = array(field_json, "children")?;
+            let child_columns = array(column, "children")?;
+            let child_types = child_fields(data_type);
+            if child_jsons.len() != child_types.len() || child_columns.len() != child_types.len() {
+                return Err(parse_error("children", column));
+            }
+            child_types
+                .iter()
+                .zip(child_jsons)
+                .zip(child_columns)
+                .map(|((field, field_json), column)| {
+                    self.column_from_json(field_json, field.data_type(), column)
+                })
+                .collect()
+        };
+
+        let builder = match data_type {
+            DataType::Null => builder,
+            DataType::Utf8 | DataType::LargeUtf8 => {
+                let strings = array(column, "DATA")?
+                    .iter()
+                    .map(|value| {
+                        value
+                            .as_str()
+                            .map(|value| value.as_bytes().to_vec())
+                            .ok_or_else(|| parse_error("DATA", value))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let (offsets, values) = variable_width(&strings, data_type == &DataType::LargeUtf8);
+                builder.add_buffer(offsets).add_buffer(values)
+            }
+            DataType::Binary | DataType::LargeBinary => {
+                let binaries = array(column, "DATA")?
+                    .iter()
+                    .map(hex_decode)
+                    .collect::<Result<Vec<_>>>()?;
+                let large = data_type == &DataType::LargeBinary;
+                let (offsets, values) = variable_width(&binaries, large);
+                builder.add_buffer(offsets).add_buffer(values)
+            }
+            DataType::FixedSizeBinary(_) => {
+                let mut values = vec![];
+                for value in array(column, "DATA")? {
+                    values.extend(hex_decode(value)?);
+                }
+                builder.add_buffer(Buffer::from_vec(values))
+            }
+            DataType::List(_) | DataType::Map(_, _) => builder
+                .add_buffer(native_buffer::<i32>(array(column, "OFFSET")?)?)
+                .child_data(children()?),
+            DataType::LargeList(_) => builder
+                .add_buffer(native_buffer::<i64>(array(column, "OFFSET")?)?)
+                .child_data(children()?),
+            DataType::FixedSizeList(_, _) | DataType::Struct(_) | DataType::RunEndEncoded(_, _) => {
+                builder.child_data(children()?)
+            }
+            DataType::Union(_, mode) => {
+                let type_ids = native_buffer::<i8>(array(column, "TYPE_ID")?)?;
+                let builder = builder.add_buffer(type_ids);
+                let builder = match mode {
+                    UnionMode::Sparse => builder,
+                    UnionMode::Dense => {
+                        builder.add_buffer(native_buffer::<i32>(array(column, "OFFSET")?)?)
+                    }
+                };
+                builder.child_data(children()?)
+            }
+            DataType::Dictionary(key_type, _) => {
+                let id = integer::<i64>(get(get(field_json, "dictionary")?, "id")?)?;
+                let values = self.dictionaries.get(&id).ok_or_else(|| {
+                    ArrowError::ParseError(format!("Dictionary {id} not found in integration JSON"))
+                })?;
+                builder
+                    .add_buffer(primitive_buffer(key_type, array(column, "DATA")?)?)
+                    .child_data(vec![values.clone()])
+            }
+            data_type => builder.add_buffer(primitive_buffer(data_type, array(column, "DATA")?)?),
+        };
+        Ok(builder.build()?)
+    }
+}
+
+/// Pair up each dictionary id in the schema with its JSON field and value type.
+fn collect_dictionary_fields<'a>(
+    field_jsons: &'a [Value],
+    fields: &Fields,
+    dictionary_fields: &mut HashMap<i64, (&'a Value, DataType)>,
+) -> Result<()> {
+    for (field_json, field) in field_jsons.iter().zip(fields) {
+        let data_type = match (field_json.get("dictionary"), field.data_type()) {
+            (Some(dictionary), DataType::Dictionary(_, value_type)) => {
+                let id = integer::<i64>(get(dictionary, "id")?)?;
+                dictionary_fields.insert(id, (field_json, value_type.as_ref().clone()));
+                value_type.as_ref()
+            }
+            (_, data_type) => data_type,
+        };
+        let children = Fields::from(child_fields(data_type));
+        if let Some(Value::Array(child_jsons)) = field_json.get("children") {
+            collect_dictionary_fields(child_jsons, &children, dictionary_fields)?;
+        }
+    }
+    Ok(())
+}
+
+fn validity_from_json(json: &Value) -> Result<Option<NullBuffer>> {
+    let validity = json
+        .as_array()
+        .ok_or_else(|| parse_error("VALIDITY", json))?
+        .iter()
+        .map(|value| match value {
+            Value::Bool(valid) => Ok(*valid),
+            value => Ok(integer::<u8>(value)? != 0),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let nulls = NullBuffer::from(validity);
+    Ok(Some(nulls).filter(|nulls| nulls.null_count() > 0))
+}
+
+/// Build the offsets and values buffers of a variable-width array.
+fn variable_width(values: &[Vec<u8>], large: bool) -> (Buffer, Buffer) {
+    let mut offsets = vec![0usize];
+    let mut length = 0;
+    for value in values {
+        length += value.len();
+        offsets.push(length);
+    }
+    let offsets = match large {
+        true => Buffer::from_iter(offsets.into_iter().map(|offset| offset as i64)),
+        false => Buffer::from_iter(offsets.into_iter().map(|offset| offset as i32)),
+    };
+    (offsets, Buffer::from_vec(values.concat()))
+}
+
+fn hex_decode(json: &Value) -> Result<Vec<u8>> {
+    let hex = json.as_str().ok_or_else(|| parse_error("DATA", json))?;
+    if hex.len() % 2 != 0 {
+        return Err(parse_error("DATA", json));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| parse_error("DATA", json))
+        })
+        .collect()
+}
+
+/// Parse an integer written either as a JSON number or, for 64-bit values, as a string.
+fn integer<T: FromStr + TryFrom<i64> + TryFrom<u64>>(json: &Value) -> Result<T> {
+    let value = match json {
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => T::try_from(value).ok(),
+            (None, Some(value)) => T::try_from(value).ok(),
+            (None, None) => None,
+        },
+        Value::String(value) => value.parse().ok(),
+        _ => None,
+    };
+    value.ok_or_else(|| parse_error("integer", json))
+}
+
+fn float(json: &Value) -> Result<f64> {
+    json.as_f64()
+        .ok_or_else(|| parse_error("floatingpoint", json))
+}
+
+fn native_buffer<T>(values: &[Value]) -> Result<Buffer>
+where
+    T: ArrowNativeType + FromStr + TryFrom<i64> + TryFrom<u64>,
+{
+    let values = values
+        .iter()
+        .map(integer::<T>)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Buffer::from_vec(values))
+}
+
+fn parse_buffer<T: ArrowNativeType>(
+    values: &[Value],
+    parse: impl Fn(&Value) -> Result<T>,
+) -> Result<Buffer> {
+    let values = values.iter().map(parse).collect::<Result<Vec<_>>>()?;
+    Ok(Buffer::from_vec(values))
+}
+
+fn primitive_buffer(data_type: &DataType, values: &[Value]) -> Result<Buffer> {
+    match data_type {
+        DataType::Boolean => {
+            let values = values
+                .iter()
+                .map(|value| value.as_bool().ok_or_else(|| parse_error("DATA", value)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(BooleanBuffer::from(values).into_inner())
+        }
+        DataType::Int8 => native_buffer::<i8>(values),
+        DataType::Int16 => native_buffer::<i16>(values),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => native_buffer::<i32>(values),
+        DataType::Interval(IntervalUnit::YearMonth) => native_buffer::<i32>(values),
+        DataType::UInt8 => native_buffer::<u8>(values),
+        DataType::UInt16 => native_buffer::<u16>(values),
+        DataType::UInt32 => native_buffer::<u32>(values),
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => native_buffer::<i64>(values),
+        DataType::UInt64 => native_buffer::<u64>(values),
+        DataType::Float16 => parse_buffer(values, |value| {
+            Ok(<Float16Type as ArrowPrimitiveType>::Native::from_f64(
+                float(value)?,
+            ))
+        }),
+        DataType::Float32 => parse_buffer(values, |value| Ok(float(value)? as f32)),
+        DataType::Float64 => parse_buffer(values, float),
+        DataType::Interval(IntervalUnit::DayTime) => parse_buffer(values, |value| {
+            Ok(IntervalDayTime::new(
+                integer(get(value, "days")?)?,
+                integer(get(value, "milliseconds")?)?,
+            ))
+        }),
+        DataType::Interval(IntervalUnit::MonthDayNano) => parse_buffer(values, |value| {
+            Ok(IntervalMonthDayNano::new(
+                integer(get(value, "months")?)?,
+                integer(get(value, "days")?)?,
+                integer(get(value, "nanoseconds")?)?,
+            ))
+        }),
+        DataType::Decimal128(_, _) => native_buffer::<i128>(values),
+        DataType::Decimal256(_, _) => parse_buffer(values, |value| {
+            let parsed = match value {
+                Value::String(value) => i256::from_string(value),
+                value => value.as_i64().map(|value| i256::from_i128(value.into())),
+            };
+            parsed.ok_or_else(|| parse_error("DATA", value))
+        }),
+        data_type => Err(unsupported(data_type)),
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, Fields, IntervalUnit, Schema, TimeUnit, UnionFields,
+    UnionMode,
+};
+use serde_json::{json, Map, Value};
+
+use crate::error::Result;
+use crate::integration_json::{array, get, string};
+
+/// Convert a schema to its JSON representation.
+///
+/// Dictionary-encoded fields are given ids in depth-first order, starting from 0.
+pub(crate) fn schema_to_json(schema: &Schema) -> Result<Value> {
+    let mut next_dictionary_id = 0;
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| field_to_json(field, &mut next_dictionary_id))
+        .collect::<Result<Vec<_>>>()?;
+    let mut json = Map::new();
+    json.insert("fields".to_string(), Value::Array(fields));
+    if !schema.metadata().is_empty() {
+        json.insert("metadata".to_string(), metadata_to_json(schema.metadata()));
+    }
+    Ok(Value::Object(json))
+}
+
+fn field_to_json(field: &Field, next_dictionary_id: &mut i64) -> Result<Value> {
+    let mut json = Map::new();
+    json.insert("name".to_string(), json!(field.name()));
+    json.insert("nullable".to_string(), json!(field.is_nullable()));
+
+    let data_type = match field.data_type() {
+        DataType::Dictionary(key_type, value_type) => {
+            let id = *next_dictionary_id;
+            *next_dictionary_id += 1;
+            json.insert(
+                "dictionary".to_string(),
+                json!({
+                    "id": id,
+                    "indexType": data_type_to_json(key_type)?,
+                    "isOrdered": field.dict_is_ordered().unwrap_or(false),
+                }),
+            );
+            value_type.as_ref()
+        }
+        data_type => data_type,
+    };
+    json.insert("type".to_string(), data_type_to_json(data_type)?);
+
+    let children = child_fields(data_type)
+        .iter()
+        .map(|child| field_to_json(child, next_dictionary_id))
+        .collect::<Result<Vec<_>>>()?;
+    json.insert("children".to_string(), Value::Array(children));
+
+    if !field.metadata().is_empty() {
+        json.insert("metadata".to_string(), metadata_to_json(field.metadata()));
+    }
+    Ok(Value::Object(json))
+}
+
+/// Returns the child fields of a nested type, in the order their columns are written.
+pub(crate) fn child_fields(data_type: &DataType) -> Vec<FieldRef> {
+    match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => vec![field.clone()],
+        DataType::Struct(fields) => fields.iter().cloned().collect(),
+        DataType::Union(fields, _) => fields.iter().map(|(_, field)| field.clone()).collect(),
+        DataType::RunEndEncoded(run_ends, values) => vec![run_ends.clone(), values.clone()],
+        _ => vec![],
+    }
+}
+
+fn time_unit_to_json(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "SECOND",
+        TimeUnit::Millisecond => "MILLISECOND",
+        TimeUnit::Microsecond => "MICROSECOND",
+        TimeUnit::Nanosecond => "NANOSECOND",
+    }
+}
+
+fn data_type_to_json(data_type: &DataType) -> Result<Value> {
+    let int = |is_signed: bool, bit_width: u8| json!({"name": "int", "isSigned": is_signed, "bitWidth": bit_width});
+    let json = match data_type {
+        DataType::Null => json!({"name": "null"}),
+        DataType::Boolean => json!({"name": "bool"}),
+        DataType::Int8 => int(true, 8),
+        DataType::Int16 => int(true, 16),
+        DataType::Int32 => int(true, 32),
+        DataType::Int64 => int(true, 64),
+        DataType::UInt8 => int(false, 8),
+        DataType::UInt16 => int(false, 16),
+        DataType::UInt32 => int(false, 32),
+        DataType::UInt64 => int(false, 64),
+        DataType::Float16 => json!({"name": "floatingpoint", "precision": "HALF"}),
+        DataType::Float32 => json!({"name": "floatingpoint", "precision": "SINGLE"}),
+        DataType::Float64 => json!({"name": "floatingpoint", "precision": "DOUBLE"}),
+        DataType::Timestamp(unit, None) => {
+            json!({"name": "timestamp", "unit": time_unit_to_json(unit)})
+        }
+        DataType::Timestamp(unit, Some(timezone)) => json!({
+            "name": "timestamp",
+            "unit": time_unit_to_json(unit),
+            "timezone": timezone.as_ref(),
+        }),
+        DataType::Date32 => json!({"name": "date", "unit": "DAY"}),
+        DataType::Date64 => json!({"name": "date", "unit": "MILLISECOND"}),
+        DataType::Time32(unit) => {
+            json!({"name": "time", "unit": time_unit_to_json(unit), "bitWidth": 32})
+        }
+        DataType::Time64(unit) => {
+            json!({"name": "time", "unit": time_unit_to_json(unit), "bitWidth": 64})
+        }
+        DataType::Duration(unit) => json!({"name": "duration", "unit": time_unit_to_json(unit)}),
+        DataType::Interval(unit) => {
+            let unit = match unit {
+                IntervalUnit::YearMonth => "YEAR_MONTH",
+                IntervalUnit::DayTime => "DAY_TIME",
+                IntervalUnit::MonthDayNano => "MONTH_DAY_NANO",
+            };
+            json!({"name": "interval", "unit": unit})
+        }
+        DataType::Binary => json!({"name": "binary"}),
+        DataType::LargeBinary => json!({"name": "largebinary"}),
+        DataType::FixedSizeBinary(byte_width) => {
+            json!({"name": "fixedsizebinary", "byteWidth": byte_width})
+        }
+        DataType::Utf8 => json!({"name": "utf8"}),
+        DataType::LargeUtf8 => json!({"name": "largeutf8"}),
+        DataType::List(_) => json!({"name": "list"}),
+        DataType::LargeList(_) => json!({"name": "largelist"}),
+        DataType::FixedSizeList(_, list_size) => {
+            json!({"name": "fixedsizelist", "listSize": list_size})
+        }
+        DataType::Struct(_) => json!({"name": "struct"}),
+        DataType::Union(fields, mode) => {
+            let mode = match mode {
+                UnionMode::Sparse => "SPARSE",
+                UnionMode::Dense => "DENSE",
+            };
+            let type_ids = fields.iter().map(|(id, _)| id).collect::<Vec<_>>();
+            json!({"name": "union", "mode": mode, "typeIds": type_ids})
+        }
+        DataType::Decimal128(precision, scale) => json!({
+            "name": "decimal",
+            "precision": precision,
+            "scale": scale,
+            "bitWidth": 128,
+        }),
+        DataType::Decimal256(precision, scale) => json!({
+            "name": "decimal",
+            "precision": precision,
+            "scale": scale,
+            "bitWidth": 256,
+        }),
+        DataType::Map(_, keys_sorted) => json!({"name": "map", "keysSorted": keys_sorted}),
+        DataType::RunEndEncoded(_, _) => json!({"name": "runendencoded"}),
+        data_type => return Err(unsupported(data_type)),
+    };
+    Ok(json)
+}
+
+fn metadata_to_json(metadata: &HashMap<String, String>) -> Value {
+    // Sort the entries so that the output is stable
+    let mut entries = metadata.iter().collect::<Vec<_>>();
+    entries.sort();
+    entries
+        .into_iter()
+        .map(|(key, value)| json!({"key": key, "value": value}))
+        .collect()
+}
+
+pub(crate) fn unsupported(data_type: &DataType) -> crate::ArrowWasmError {
+    ArrowError::NotYetImplemented(format!(
+        "Data type {data_type} is not supported in integration JSON"
+    ))
+    .into()
+}
+
+/// Parse a schema from its JSON representation.
+pub(crate) fn schema_from_json(json: &Value) -> Result<Schema> {
+    let fields = array(json, "fields")?
+        .iter()
+        .map(field_from_json)
+        .collect::<Result<Vec<_>>>()?;
+    let metadata = match json.get("metadata") {
+        Some(metadata) => metadata_from_json(metadata)?,
+        None => HashMap::new(),
+    };
+    Ok(Schema::new_with_metadata(fields, metadata))
+}
+
+pub(crate) fn field_from_json(json: &Value) -> Result<Field> {
+    let name = string(json, "name")?;
+    let nullable = get(json, "nullable")?
+        .as_bool()
+        .ok_or_else(|| parse_error("nullable", json))?;
+    let children = match json.get("children") {
+        Some(_) => array(json, "children")?
+            .iter()
+            .map(field_from_json)
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![],
+    };
+
+    let mut data_type = data_type_from_json(get(json, "type")?, children)?;
+    let mut dict_is_ordered = false;
+    if let Some(dictionary) = json.get("dictionary") {
+        let key_type = data_type_from_json(get(dictionary, "indexType")?, vec![])?;
+        data_type = DataType::Dictionary(Box::new(key_type), Box::new(data_type));
+        dict_is_ordered = dictionary
+            .get("isOrdered")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+    }
+
+    let mut field = Field::new(name, data_type, nullable).with_dict_is_ordered(dict_is_ordered);
+    if let Some(metadata) = json.get("metadata") {
+        field.set_metadata(metadata_from_json(metadata)?);
+    }
+    Ok(field)
+}
+
+fn time_unit_from_json(json: &Value) -> Result<TimeUnit> {
+    match string(json, "unit")? {
+        "SECOND" => Ok(TimeUnit::Second),
+        "MILLISECOND" => Ok(TimeUnit::Millisecond),
+        "MICROSECOND" => Ok(TimeUnit::Microsecond),
+        "NANOSECOND" => Ok(TimeUnit::Nanosecond),
+        _ => Err(parse_error("unit", json)),
+    }
+}
+
+fn int_from_json<T: TryFrom<i64>>(json: &Value, key: &str) -> Result<T> {
+    get(json, key)?
+        .as_i64()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| parse_error(key, json))
+}
+
+fn data_type_from_json(json: &Value, mut children: Vec<Field>) -> Result<DataType> {
+    let mut child = |name: &str| {
+        if children.len() != 1 {
+            return Err(ArrowError::ParseError(format!(
+                "Expected one child for {name} type, found {}",
+                children.len()
+            )));
+        }
+        Ok(Arc::new(children.remove(0)))
+    };
+
+    let data_type = match string(json, "name")? {
+        "null" => DataType::Null,
+        "bool" => DataType::Boolean,
+        "int" => {
+            let is_signed = get(json, "isSigned")?
+                .as_bool()
+                .ok_or_else(|| parse_error("isSigned", json))?;
+            match (is_signed, int_from_json::<u8>(json, "bitWidth")?) {
+                (true, 8) => DataType::Int8,
+                (true, 16) => DataType::Int16,
+                (true, 32) => DataType::Int32,
+                (true, 64) => DataType::Int64,
+                (false, 8) => DataType::UInt8,
+                (false, 16) => DataType::UInt16,
+                (false, 32) => DataType::UInt32,
+                (false, 64) => DataType::UInt64,
+                _ => return Err(parse_error("bitWidth", json)),
+            }
+        }
+        "floatingpoint" => match string(json, "precision")? {
+            "HALF" => DataType::Float16,
+            "SINGLE" => DataType::Float32,
+            "DOUBLE" => DataType::Float64,
+            _ => return Err(parse_error("precision", json)),
+        },
+        "timestamp" => {
+            let timezone = json.get("timezone").and_then(Value::as_str);
+            DataType::Timestamp(time_unit_from_json(json)?, timezone.map(Into::into))
+        }
+        "date" => match string(json, "unit")? {
+            "DAY" => DataType::Date32,
+            "MILLISECOND" => DataType::Date64,
+            _ => return Err(parse_error("unit", json)),
+        },
+        "time" => match int_from_json::<u8>(json, "bitWidth")? {
+            32 => DataType::Time32(time_unit_from_json(json)?),
+            64 => DataType::Time64(time_unit_from_json(json)?),
+            _ => return Err(parse_error("bitWidth", json)),
+        },
+        "duration" => DataType::Duration(time_unit_from_json(json)?),
+        "interval" => match string(json, "unit")? {
+            "YEAR_MONTH" => DataType::Interval(IntervalUnit::YearMonth),
+            "DAY_TIME" => DataType::Interval(IntervalUnit::DayTime),
+            "MONTH_DAY_NANO" => DataType::Interval(IntervalUnit::MonthDayNano),
+            _ => return Err(parse_error("unit", json)),
+        },
+        "binary" => DataType::Binary,
+        "largebinary" => DataType::LargeBinary,
+        "fixedsizebinary" => DataType::FixedSizeBinary(int_from_json(json, "byteWidth")?),
+        "utf8" => DataType::Utf8,
+        "largeutf8" => DataType::LargeUtf8,
+        "list" => DataType::List(child("list")?),
+        "largelist" => DataType::LargeList(child("largelist")?),
+        "fixedsizelist" => {
+            DataType::FixedSizeList(child("fixedsizelist")?, int_from_json(json, "listSize")?)
+        }
+        "struct" => DataType::Struct(Fields::from(children)),
+        "union" => {
+            let mode = match string(json, "mode")? {
+                "SPARSE" => UnionMode::Sparse,
+                "DENSE" => UnionMode::Dense,
+                _ => return Err(parse_error("mode", json)),
+            };
+            let type_ids = array(json, "typeIds")?
+                .iter()
+                .map(|id| {
+                    id.as_i64()
+                        .and_then(|id| i8::try_from(id).ok())
+                        .ok_or_else(|| parse_error("typeIds", json))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let fields = UnionFields::try_new(type_ids, children)
+                .map_err(|_| parse_error("typeIds", json))?;
+            DataType::Union(fields, mode)
+        }
+        "decimal" => {
+            let precision = int_from_json(json, "precision")?;
+            let scale = int_from_json(json, "scale")?;
+            // The bit width defaults to 128 for compatibility with older writers
+            match json.get("bitWidth").and_then(Value::as_i64).unwrap_or(128) {
+                128 => DataType::Decimal128(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                _ => return Err(parse_error("bitWidth", json)),
+            }
+        }
+        "map" => {
+            let keys_sorted = get(json, "keysSorted")?
+                .as_bool()
+                .ok_or_else(|| parse_error("keysSorted", json))?;
+            DataType::Map(child("map")?, keys_sorted)
+        }
+        "runendencoded" => {
+            if children.len() != 2 {
+                return Err(ArrowError::ParseError(format!(
+                    "Expected two children for runendencoded type, found {}",
+                    children.len()
+                ))
+                .into());
+            }
+            let values = children.pop().unwrap();
+            let run_ends = children.pop().unwrap();
+            DataType::RunEndEncoded(Arc::new(run_ends), Arc::new(values))
+        }
+        name => {
+            return Err(ArrowError::ParseError(format!(
+                "Unsupported type in integration JSON: {name}"
+            ))
+            .into())
+        }
+    };
+    Ok(data_type)
+}
+
+fn metadata_from_json(json: &Value) -> Result<HashMap<String, String>> {
+    json.as_array()
+        .ok_or_else(|| parse_error("metadata", json))?
+        .iter()
+        .map(|entry| {
+            Ok((
+                string(entry, "key")?.to_string(),
+                string(entry, "value")?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+pub(crate) fn parse_error(key: &str, json: &Value) -> crate::ArrowWasmError {
+    ArrowError::ParseError(format!(
+        "Invalid value for {key:?} in integration JSON: {json}"
+    ))
+    .into()
+}
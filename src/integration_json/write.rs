@@ -0,0 +1,315 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Int16Type, Int32Type, Int64Type, RunEndIndexType};
+use arrow_array::{
+    Array, ArrayRef, ArrowPrimitiveType, OffsetSizeTrait, PrimitiveArray, RecordBatch, RunArray,
+};
+use arrow_buffer::{
+    i256, ArrowNativeType, IntervalDayTime, IntervalMonthDayNano, OffsetBuffer, ScalarBuffer,
+};
+use arrow_schema::{ArrowError, DataType, IntervalUnit, UnionMode};
+use serde_json::{json, Map, Value};
+
+use crate::error::Result;
+use crate::integration_json::schema::{child_fields, unsupported};
+
+/// Converts record batches to JSON, collecting the dictionaries they reference along the way.
+#[derive(Default)]
+pub(crate) struct BatchWriter {
+    /// The dictionaries written so far as `(id, column)`, with nested dictionaries before the
+    /// dictionaries whose values reference them.
+    pub(crate) dictionaries: Vec<(i64, Value)>,
+    next_dictionary_id: i64,
+}
+
+impl BatchWriter {
+    pub(crate) fn batch_to_json(&mut self, batch: &RecordBatch) -> Result<Value> {
+        // Dictionary ids are assigned in the same depth-first order as in the schema
+        self.next_dictionary_id = 0;
+        let columns = batch
+            .schema()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, column)| self.column_to_json(field.name(), column.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(json!({"count": batch.num_rows(), "columns": columns}))
+    }
+
+    fn column_to_json(&mut self, name: &str, array: &dyn Array) -> Result<Value> {
+        let mut json = Map::new();
+        json.insert("name".to_string(), json!(name));
+        json.insert("count".to_string(), json!(array.len()));
+
+        let data_type = array.data_type();
+        // Null, union and run-end encoded arrays have no validity bitmap
+        if !matches!(
+            data_type,
+            DataType::Null | DataType::Union(_, _) | DataType::RunEndEncoded(_, _)
+        ) {
+            let validity = (0..array.len())
+                .map(|i| json!(array.is_valid(i) as u8))
+                .collect();
+            json.insert("VALIDITY".to_string(), Value::Array(validity));
+        }
+
+        match data_type {
+            DataType::Null => {}
+            DataType::Utf8 => {
+                let array = array.as_string::<i32>();
+                let offsets = offsets_to_json(array.offsets(), false);
+                json.insert("OFFSET".to_string(), offsets);
+                let data = (0..array.len()).map(|i| json!(array.value(i))).collect();
+                json.insert("DATA".to_string(), data);
+            }
+            DataType::LargeUtf8 => {
+                let array = array.as_string::<i64>();
+                let offsets = offsets_to_json(array.offsets(), true);
+                json.insert("OFFSET".to_string(), offsets);
+                let data = (0..array.len()).map(|i| json!(array.value(i))).collect();
+                json.insert("DATA".to_string(), data);
+            }
+            DataType::Binary => {
+                let array = array.as_binary::<i32>();
+                let offsets = offsets_to_json(array.offsets(), false);
+                json.insert("OFFSET".to_string(), offsets);
+                let data = (0..array.len())
+                    .map(|i| hex_encode(array.value(i)))
+                    .collect();
+                json.insert("DATA".to_string(), data);
+            }
+            DataType::LargeBinary => {
+                let array = array.as_binary::<i64>();
+                let offsets = offsets_to_json(array.offsets(), true);
+                json.insert("OFFSET".to_string(), offsets);
+                let data = (0..array.len())
+                    .map(|i| hex_encode(array.value(i)))
+                    .collect();
+                json.insert("DATA".to_string(), data);
+            }
+            DataType::FixedSizeBinary(_) => {
+                let array = array.as_fixed_size_binary();
+                let data = (0..array.len())
+                    .map(|i| hex_encode(array.value(i)))
+                    .collect();
+                json.insert("DATA".to_string(), data);
+            }
+            DataType::List(_) => {
+                let array = array.as_list::<i32>();
+                let (offsets, values) = list_values(array.offsets(), array.values().as_ref());
+                json.insert("OFFSET".to_string(), offsets_to_json(&offsets, false));
+                let children = self.children_to_json(data_type, vec![values.as_ref()])?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::LargeList(_) => {
+                let array = array.as_list::<i64>();
+                let (offsets, values) = list_values(array.offsets(), array.values().as_ref());
+                json.insert("OFFSET".to_string(), offsets_to_json(&offsets, true));
+                let children = self.children_to_json(data_type, vec![values.as_ref()])?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::Map(_, _) => {
+                let array = array.as_map();
+                let (offsets, entries) = list_values(array.offsets(), array.entries());
+                json.insert("OFFSET".to_string(), offsets_to_json(&offsets, false));
+                let children = self.children_to_json(data_type, vec![entries.as_ref()])?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::FixedSizeList(_, _) => {
+                let array = array.as_fixed_size_list();
+                let children = self.children_to_json(data_type, vec![array.values().as_ref()])?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::Struct(_) => {
+                let array = array.as_struct();
+                let children = self.children_to_json(
+                    data_type,
+                    array.columns().iter().map(|c| c.as_ref()).collect(),
+                )?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::Union(fields, mode) => {
+                let array = array.as_union();
+                let type_ids = array.type_ids().iter().map(|id| json!(id)).collect();
+                json.insert("TYPE_ID".to_string(), Value::Array(type_ids));
+                if *mode == UnionMode::Dense {
+                    let offsets = array.offsets().unwrap().iter().map(|o| json!(o)).collect();
+                    json.insert("OFFSET".to_string(), Value::Array(offsets));
+                }
+                let children = fields.iter().map(|(id, _)| array.child(id).as_ref());
+                let children = self.children_to_json(data_type, children.collect())?;
+                json.insert("children".to_string(), children);
+            }
+            DataType::Dictionary(_, _) => {
+                let array = array.as_any_dictionary();
+                let id = self.next_dictionary_id;
+                self.next_dictionary_id += 1;
+                let values = self.column_to_json(name, array.values().as_ref())?;
+                self.insert_dictionary(id, values)?;
+                json.insert("DATA".to_string(), primitive_to_json(array.keys())?);
+            }
+            DataType::RunEndEncoded(run_ends, _) => {
+                let (run_ends, values) = match run_ends.data_type() {
+                    DataType::Int16 => run_end_encoded(array.as_run::<Int16Type>()),
+                    DataType::Int32 => run_end_encoded(array.as_run::<Int32Type>()),
+                    DataType::Int64 => run_end_encoded(array.as_run::<Int64Type>()),
+                    data_type => return Err(unsupported(data_type)),
+                };
+                let children =
+                    self.children_to_json(data_type, vec![run_ends.as_ref(), values.as_ref()])?;
+                json.insert("children".to_string(), children);
+            }
+            _ => {
+                json.insert("DATA".to_string(), primitive_to_json(array)?);
+            }
+        }
+        Ok(Value::Object(json))
+    }
+
+    fn children_to_json(&mut self, data_type: &DataType, arrays: Vec<&dyn Array>) -> Result<Value> {
+        child_fields(data_type)
+            .iter()
+            .zip(arrays)
+            .map(|(field, array)| self.column_to_json(field.name(), array))
+            .collect()
+    }
+
+    /// Record the values of a dictionary, checking that every batch uses the same dictionary.
+    fn insert_dictionary(&mut self, id: i64, values: Value) -> Result<()> {
+        match self.dictionaries.iter().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) if *existing != values => Err(ArrowError::NotYetImplemented(
+                format!("Dictionary {id} changes between batches, which integration JSON does not support"),
+            )
+            .into()),
+            Some(_) => Ok(()),
+            None => {
+                self.dictionaries.push((id, values));
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the offsets rebased to start at zero, and the slice of `values` they reference.
+fn list_values<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    values: &dyn Array,
+) -> (Vec<O>, ArrayRef) {
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let rebased = offsets.iter().map(|offset| *offset - start).collect();
+    let values = values.slice(start.as_usize(), (end - start).as_usize());
+    (rebased, values)
+}
+
+/// Returns the physical run ends and values of a possibly sliced run-end encoded array.
+fn run_end_encoded<R: RunEndIndexType>(array: &RunArray<R>) -> (ArrayRef, ArrayRef) {
+    if array.is_empty() {
+        let run_ends = PrimitiveArray::<R>::new(ScalarBuffer::from(vec![]), None);
+        return (Arc::new(run_ends), array.values().slice(0, 0));
+    }
+    let start = array.get_start_physical_index();
+    let end = array.get_end_physical_index();
+    let offset = array.offset();
+    let run_ends = array.run_ends().values()[start..=end]
+        .iter()
+        .map(|run_end| {
+            let run_end = (run_end.as_usize() - offset).min(array.len());
+            R::Native::usize_as(run_end)
+        })
+        .collect::<Vec<_>>();
+    let run_ends = PrimitiveArray::<R>::new(ScalarBuffer::from(run_ends), None);
+    (
+        Arc::new(run_ends),
+        array.values().slice(start, end - start + 1),
+    )
+}
+
+/// Offsets of large types are written as strings, like other 64-bit integers.
+fn offsets_to_json<O: OffsetSizeTrait>(offsets: &[O], large: bool) -> Value {
+    let start = offsets.first().copied().unwrap_or_default();
+    offsets
+        .iter()
+        .map(|offset| {
+            let offset = (*offset - start).as_usize();
+            match large {
+                true => json!(offset.to_string()),
+                false => json!(offset),
+            }
+        })
+        .collect()
+}
+
+fn hex_encode(bytes: &[u8]) -> Value {
+    Value::String(bytes.iter().map(|byte| format!("{byte:02X}")).collect())
+}
+
+/// Returns the values of a primitive array, including those in null slots.
+fn values<T: ArrowNativeType>(array: &dyn Array) -> ScalarBuffer<T> {
+    let data = array.to_data();
+    ScalarBuffer::new(data.buffers()[0].clone(), data.offset(), data.len())
+}
+
+fn numbers<T: ArrowNativeType + Into<Value>>(array: &dyn Array) -> Value {
+    values::<T>(array)
+        .iter()
+        .map(|value| (*value).into())
+        .collect()
+}
+
+/// 64-bit integers and decimals are written as strings, since they may not fit in a JSON number.
+fn strings<T: ArrowNativeType + ToString>(array: &dyn Array) -> Value {
+    let values = values::<T>(array);
+    values
+        .iter()
+        .map(|value| Value::String(value.to_string()))
+        .collect()
+}
+
+fn primitive_to_json(array: &dyn Array) -> Result<Value> {
+    let json = match array.data_type() {
+        DataType::Boolean => array.as_boolean().values().iter().collect(),
+        DataType::Int8 => numbers::<i8>(array),
+        DataType::Int16 => numbers::<i16>(array),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => numbers::<i32>(array),
+        DataType::Interval(IntervalUnit::YearMonth) => numbers::<i32>(array),
+        DataType::UInt8 => numbers::<u8>(array),
+        DataType::UInt16 => numbers::<u16>(array),
+        DataType::UInt32 => numbers::<u32>(array),
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => strings::<i64>(array),
+        DataType::UInt64 => strings::<u64>(array),
+        DataType::Float16 => {
+            let values = values::<<Float16Type as ArrowPrimitiveType>::Native>(array);
+            values.iter().map(|value| json!(value.to_f32())).collect()
+        }
+        DataType::Float32 => numbers::<f32>(array),
+        DataType::Float64 => numbers::<f64>(array),
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let values = values::<IntervalDayTime>(array);
+            let values = values
+                .iter()
+                .map(|value| json!({"days": value.days, "milliseconds": value.milliseconds}));
+            values.collect()
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let values = values::<IntervalMonthDayNano>(array);
+            let values = values.iter().map(|value| {
+                json!({
+                    "months": value.months,
+                    "days": value.days,
+                    "nanoseconds": value.nanoseconds,
+                })
+            });
+            values.collect()
+        }
+        DataType::Decimal128(_, _) => strings::<i128>(array),
+        DataType::Decimal256(_, _) => strings::<i256>(array),
+        data_type => return Err(unsupported(data_type)),
+    };
+    Ok(json)
+}
@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_ipc::convert::fb_to_schema;
+use arrow_ipc::reader::FileDecoder;
+use arrow_ipc::{root_as_message, Block, MessageHeader};
+use arrow_schema::{ArrowError, SchemaRef};
+use wasm_bindgen::prelude::*;
+
+use crate::error::Result;
+use crate::ipc::footer::{block_range, footer_range, FileMetadata, TRAILER_LENGTH};
+use crate::ipc::message::{add_lengths, body_length, read_u32, CONTINUATION_MARKER};
+use crate::ipc::reader::{is_ipc_file, read_batches};
+use crate::ipc::ReadOptions;
+
+/// A buffer allocated in WebAssembly memory that JavaScript can write into directly.
+///
+/// Allocating the buffer up front lets an Arrow IPC payload be copied into WebAssembly memory
+/// exactly once, for example while it is streamed from `fetch`. {@linkcode Table.fromIPCBuffer}
+/// then decodes it without copying: the arrays of the table reference slices of this buffer.
+///
+/// **Example**:
+///
+/// ```ts
+/// const response = await fetch(url);
+/// const buffer = allocBuffer(Number(response.headers.get("Content-Length")));
+/// let offset = 0;
+/// for await (const chunk of response.body) {
+///   buffer.view().set(chunk, offset);
+///   offset += chunk.length;
+/// }
+/// const table = Table.fromIPCBuffer(buffer);
+/// ```
+#[wasm_bindgen]
+pub struct WasmBuffer(MutableBuffer);
+
+impl WasmBuffer {
+    /// The contents of the buffer.
+    pub fn as_slice_mut(&mut self) -> &mut [u8] {
+        self.0.as_slice_mut()
+    }
+
+    /// Convert into an immutable [Buffer] without copying.
+    pub fn into_buffer(self) -> Buffer {
+        self.0.into()
+    }
+}
+
+#[wasm_bindgen]
+impl WasmBuffer {
+    /// The length of the buffer in bytes.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// A `Uint8Array` view of the buffer in WebAssembly memory.
+    ///
+    /// The view is invalidated whenever WebAssembly memory grows, which can happen on any call
+    /// into this module, so call `view()` again rather than holding on to the view across such
+    /// calls.
+    #[wasm_bindgen]
+    pub fn view(&mut self) -> js_sys::Uint8Array {
+        unsafe { js_sys::Uint8Array::view_mut_raw(self.0.as_mut_ptr(), self.0.len()) }
+    }
+}
+
+/// Allocate a zero-filled buffer of `length` bytes in WebAssembly memory.
+///
+/// The buffer is aligned to 64 bytes, so that the buffers of an Arrow IPC payload written into it
+/// are suitably aligned to be used in place.
+#[wasm_bindgen(js_name = allocBuffer)]
+pub fn alloc_buffer(length: usize) -> WasmBuffer {
+    WasmBuffer(MutableBuffer::from_len_zeroed(length))
+}
+
+/// Read an Arrow IPC buffer in either the Stream or the File format without copying it.
+///
+/// The arrays of the returned batches reference slices of `buf` rather than copies of it, except
+/// where a body is compressed or a buffer is not aligned for its data type.
+pub fn read_buffer(buf: Buffer, options: &ReadOptions) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    if is_ipc_file(&buf) {
+        read_file_buffer(buf, options)
+    } else {
+        read_stream_buffer(buf, options)
+    }
+}
+
+/// Returns the slice of `buf` with the given byte range, or an error if it is out of bounds.
+fn slice(buf: &Buffer, (offset, length): (u64, usize)) -> std::result::Result<Buffer, ArrowError> {
+    let offset = offset as usize;
+    if offset
+        .checked_add(length)
+        .map_or(true, |end| end > buf.len())
+    {
+        return Err(ArrowError::IpcError(
+            "Unexpected end of IPC buffer".to_string(),
+        ));
+    }
+    Ok(buf.slice_with_length(offset, length))
+}
+
+/// Apply `projection` to `decoder`, leaving validation to `read_batches`.
+fn configure_decoder(decoder: FileDecoder, projection: Option<Vec<usize>>) -> FileDecoder {
+    let decoder = match projection {
+        Some(projection) => decoder.with_projection(projection),
+        None => decoder,
+    };
+    // Safety: the batches are validated at the requested level by `read_batches`
+    unsafe { decoder.with_skip_validation(true) }
+}
+
+fn read_file_buffer(buf: Buffer, options: &ReadOptions) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    if buf.len() < TRAILER_LENGTH {
+        return Err(ArrowError::IpcError(
+            "Buffer is too small to be an Arrow IPC File".to_string(),
+        )
+        .into());
+    }
+    let trailer = &buf[buf.len() - TRAILER_LENGTH..];
+    let footer = slice(&buf, footer_range(buf.len() as u64, trailer)?)?;
+    let mut metadata = FileMetadata::try_new(&footer)?;
+    let projection = options.projection(&metadata.schema)?;
+    metadata.decoder = configure_decoder(metadata.decoder, projection.clone());
+
+    for block in &metadata.dictionaries {
+        let message = slice(&buf, block_range(block))?;
+        metadata.decoder.read_dictionary(block, &message)?;
+    }
+    let batches = metadata.batches.iter().map(|block| {
+        let message = slice(&buf, block_range(block))?;
+        metadata.read_record_batch(block, &message)
+    });
+    read_batches(metadata.schema.clone(), projection, batches, options)
+}
+
+/// An encapsulated message of an IPC Stream, along with its location in the stream.
+struct StreamMessage {
+    header_type: MessageHeader,
+    /// The location of the message relative to the start of `buf`.
+    block: Block,
+    buf: Buffer,
+}
+
+/// Split an IPC Stream into its encapsulated messages, stopping at the end-of-stream marker.
+///
+/// Returns the schema from the first message along with every message that follows it.
+fn stream_messages(buf: &Buffer) -> Result<(SchemaRef, FileDecoder, Vec<StreamMessage>)> {
+    let mut messages = vec![];
+    let mut schema = None;
+    let mut offset = 0;
+    while offset < buf.len() {
+        let (prefix_length, metadata_length) = match read_u32(buf, offset)? {
+            CONTINUATION_MARKER => (8, read_u32(buf, add_lengths(offset, 4)?)? as usize),
+            // The legacy format has no continuation marker
+            metadata_length => (4, metadata_length as usize),
+        };
+        if metadata_length == 0 {
+            // End-of-stream marker
+            break;
+        }
+
+        let header_length = add_lengths(prefix_length, metadata_length)?;
+        let metadata = buf
+            .get(add_lengths(offset, prefix_length)?..add_lengths(offset, header_length)?)
+            .ok_or_else(|| ArrowError::IpcError("Unexpected end of IPC message".to_string()))?;
+        let message = root_as_message(metadata).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+        let body_length = body_length(&message)?;
+        let message_length = add_lengths(header_length, body_length)?;
+
+        match (message.header_type(), &schema) {
+            (MessageHeader::Schema, None) => {
+                let ipc_schema = message.header_as_schema().unwrap();
+                let decoder_schema: SchemaRef = Arc::new(fb_to_schema(ipc_schema));
+                schema = Some((decoder_schema, message.version()));
+            }
+            (MessageHeader::Schema, Some(_)) => {
+                return Err(ArrowError::IpcError(
+                    "Unexpected schema message after the start of the IPC Stream".to_string(),
+                )
+                .into())
+            }
+            (_, None) => {
+                return Err(ArrowError::IpcError(
+                    "IPC Stream does not start with a schema message".to_string(),
+                )
+                .into())
+            }
+            (header_type, Some(_)) => {
+                let metadata_length = i32::try_from(header_length).map_err(|_| {
+                    ArrowError::IpcError(format!(
+                        "IPC message metadata of {header_length} bytes is too large"
+                    ))
+                })?;
+                let block = Block::new(0, metadata_length, message.bodyLength());
+                messages.push(StreamMessage {
+                    header_type,
+                    block,
+                    buf: slice(buf, (offset as u64, message_length))?,
+                });
+            }
+        }
+        offset = add_lengths(offset, message_length)?;
+    }
+
+    let (schema, version) = schema
+        .ok_or_else(|| ArrowError::IpcError("IPC Stream has no schema message".to_string()))?;
+    let decoder = FileDecoder::new(schema.clone(), version);
+    Ok((schema, decoder, messages))
+}
+
+fn read_stream_buffer(buf: Buffer, options: &ReadOptions) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let (schema, decoder, messages) = stream_messages(&buf)?;
+    let projection = options.projection(&schema)?;
+    let mut decoder = configure_decoder(decoder, projection.clone());
+
+    // Dictionaries may be interleaved with record batches, so decode them in order
+    let batches = messages.into_iter().filter_map(move |message| {
+        let StreamMessage {
+            header_type,
+            block,
+            buf,
+        } = message;
+        match header_type {
+            MessageHeader::DictionaryBatch => match decoder.read_dictionary(&block, &buf) {
+                Ok(()) => None,
+                Err(err) => Some(Err(err)),
+            },
+            MessageHeader::RecordBatch => decoder.read_record_batch(&block, &buf).transpose(),
+            header_type => Some(Err(ArrowError::IpcError(format!(
+                "Unsupported IPC message type: {header_type:?}"
+            )))),
+        }
+    });
+    read_batches(schema, projection, batches, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array, DictionaryArray, Int64Array};
+    use arrow_ipc::writer::IpcWriteOptions;
+
+    use super::*;
+    use crate::column::ColumnSelector;
+    use crate::ipc::{encode_schema, read_stream, write_file, write_stream};
+    use crate::test_util::batch_message_with_body_length;
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let dictionary: DictionaryArray<Int32Type> =
+                    vec!["a", "b", "a"].into_iter().collect();
+                RecordBatch::try_from_iter([
+                    ("a", Arc::new(Int64Array::from(vec![i, i + 1, i + 2])) as _),
+                    ("b", Arc::new(dictionary) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// Copy `bytes` into a new [WasmBuffer] and decode it, checking that the decoded arrays
+    /// point into the buffer.
+    fn read_in_place(bytes: &[u8], options: &ReadOptions) -> (SchemaRef, Vec<RecordBatch>) {
+        let mut buffer = alloc_buffer(bytes.len());
+        buffer.as_slice_mut().copy_from_slice(bytes);
+        let buffer = buffer.into_buffer();
+        let range = buffer.as_ptr() as usize..buffer.as_ptr() as usize + buffer.len();
+
+        let (schema, batches) = read_buffer(buffer, options).unwrap();
+        for batch in &batches {
+            let values = batch
+                .column_by_name("a")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap()
+                .values();
+            assert!(range.contains(&(values.as_ptr() as usize)));
+        }
+        (schema, batches)
+    }
+
+    #[test]
+    fn read_stream_in_place() {
+        let batches = batches();
+        let schema = batches[0].schema();
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let (read_schema, read_batches) = read_in_place(&stream, &ReadOptions::default());
+        assert_eq!(read_schema, schema);
+        assert_eq!(read_batches, batches);
+
+        let options = ReadOptions {
+            columns: Some(vec![ColumnSelector::Name("a".to_string())]),
+            offset: Some(2),
+            limit: Some(3),
+            ..Default::default()
+        };
+        let (read_schema, read_batches) = read_in_place(&stream, &options);
+        assert_eq!(read_schema.fields().len(), 1);
+        assert_eq!(read_batches, read_stream(&stream, &options).unwrap().1);
+    }
+
+    #[test]
+    fn read_file_in_place() {
+        let batches = batches();
+        let schema = batches[0].schema();
+        let file = write_file(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let (read_schema, read_batches) = read_in_place(&file, &ReadOptions::default());
+        assert_eq!(read_schema, schema);
+        assert_eq!(read_batches, batches);
+
+        // Truncated buffers are rejected rather than read out of bounds
+        let truncated = Buffer::from_slice_ref(&file[20..]);
+        assert!(read_buffer(truncated, &ReadOptions::default()).is_err());
+        let truncated = Buffer::from_slice_ref(&file[..file.len() - 1]);
+        assert!(read_buffer(truncated, &ReadOptions::default()).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_body_length() {
+        for body_length in [-1, i64::MIN, i64::MAX, u32::MAX as i64 + 1] {
+            let (schema, message) = batch_message_with_body_length(body_length);
+            let mut stream = encode_schema(&schema, &IpcWriteOptions::default()).unwrap();
+            stream.extend(message);
+            let stream = Buffer::from_vec(stream);
+            assert!(read_buffer(stream, &ReadOptions::default()).is_err());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use arrow_buffer::Buffer;
+use arrow_ipc::reader::StreamDecoder;
+use wasm_bindgen::prelude::*;
+
+use crate::error::{Result, WasmResult};
+
+/// A push-based decoder for the Arrow IPC Stream format.
+///
+/// Bytes can be pushed into the decoder in chunks of any size as they arrive, for example from a
+/// `fetch` response body or a WebSocket. Each {@linkcode RecordBatch} is queued as soon as all of
+/// its bytes have been pushed, so that a large remote stream can be processed progressively
+/// instead of waiting for the full download.
+///
+/// **Example**:
+///
+/// ```ts
+/// const decoder = new IPCStreamDecoder();
+/// const response = await fetch(url);
+/// for await (const chunk of response.body) {
+///   decoder.push(chunk);
+///   let batch;
+///   while ((batch = decoder.nextBatch())) {
+///     render(batch);
+///   }
+/// }
+/// decoder.finish();
+/// ```
+#[wasm_bindgen]
+pub struct IPCStreamDecoder {
+    decoder: StreamDecoder,
+    batches: VecDeque<arrow_array::RecordBatch>,
+}
+
+impl IPCStreamDecoder {
+    /// Decode a chunk of bytes, queueing every record batch it completes.
+    ///
+    /// Returns the number of record batches completed by this chunk.
+    pub fn push_bytes(&mut self, chunk: &[u8]) -> Result<usize> {
+        let mut buffer = Buffer::from_slice_ref(chunk);
+        let mut num_batches = 0;
+        while !buffer.is_empty() {
+            if let Some(batch) = self.decoder.decode(&mut buffer)? {
+                self.batches.push_back(batch);
+                num_batches += 1;
+            }
+        }
+        Ok(num_batches)
+    }
+
+    /// Remove and return the oldest decoded record batch, if any.
+    pub fn pop_batch(&mut self) -> Option<arrow_array::RecordBatch> {
+        self.batches.pop_front()
+    }
+}
+
+impl Default for IPCStreamDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl IPCStreamDecoder {
+    /// Create a new decoder that has not yet seen any bytes.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            decoder: StreamDecoder::new(),
+            batches: VecDeque::new(),
+        }
+    }
+
+    /// Push a chunk of bytes into the decoder.
+    ///
+    /// The chunk is copied into WebAssembly memory, so it may be reused after this call.
+    ///
+    /// @param chunk the next bytes of the IPC stream.
+    /// @returns the number of record batches completed by this chunk.
+    #[wasm_bindgen]
+    pub fn push(&mut self, chunk: &[u8]) -> WasmResult<usize> {
+        Ok(self.push_bytes(chunk)?)
+    }
+
+    /// The schema of the stream, or `null` if the schema message has not been fully pushed yet.
+    #[cfg(feature = "schema")]
+    #[wasm_bindgen(getter)]
+    pub fn schema(&self) -> Option<crate::Schema> {
+        self.decoder.schema().map(crate::Schema::new)
+    }
+
+    /// The number of decoded record batches that have not yet been pulled with {@linkcode
+    /// IPCStreamDecoder.nextBatch}.
+    #[wasm_bindgen(getter, js_name = numPendingBatches)]
+    pub fn num_pending_batches(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// Pull the oldest decoded record batch.
+    ///
+    /// @returns a RecordBatch or `null` if no complete record batch is pending.
+    #[wasm_bindgen(js_name = nextBatch)]
+    pub fn next_batch(&mut self) -> Option<crate::RecordBatch> {
+        self.pop_batch().map(crate::RecordBatch::new)
+    }
+
+    /// Pull every decoded record batch that is pending.
+    #[wasm_bindgen(js_name = drainBatches)]
+    pub fn drain_batches(&mut self) -> Vec<crate::RecordBatch> {
+        self.batches
+            .drain(..)
+            .map(crate::RecordBatch::new)
+            .collect()
+    }
+
+    /// Signal the end of the input.
+    ///
+    /// Returns an error if the bytes pushed so far end partway through a message. Record batches
+    /// that are still pending can be pulled after this call.
+    #[wasm_bindgen]
+    pub fn finish(&mut self) -> WasmResult<()> {
+        Ok(self.decoder.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_ipc::writer::IpcWriteOptions;
+
+    use super::*;
+    use crate::ipc::write_stream;
+    use crate::test_util::three_batch_table;
+
+    #[test]
+    fn decode_byte_by_byte() {
+        let (schema, batches) = three_batch_table();
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+
+        let mut decoder = IPCStreamDecoder::new();
+        let mut decoded = vec![];
+        for byte in stream.chunks(1) {
+            decoder.push(byte).unwrap();
+            while let Some(batch) = decoder.next_batch() {
+                decoded.push(batch.into_inner());
+            }
+        }
+        #[cfg(feature = "schema")]
+        assert_eq!(decoder.schema().unwrap().into_inner(), schema);
+        assert_eq!(decoded, batches);
+        assert!(decoder.finish().is_ok());
+
+        // Batches stay pending until they are pulled
+        let mut decoder = IPCStreamDecoder::new();
+        assert_eq!(decoder.push(&stream).unwrap(), 3);
+        assert_eq!(decoder.num_pending_batches(), 3);
+        let drained = decoder
+            .drain_batches()
+            .into_iter()
+            .map(crate::RecordBatch::into_inner)
+            .collect::<Vec<_>>();
+        assert_eq!(drained, batches);
+        assert!(decoder.next_batch().is_none());
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch};
+    use arrow_ipc::writer::IpcWriteOptions;
+    use arrow_schema::{DataType, Field, Schema};
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::ipc::write_stream;
+
+    #[wasm_bindgen_test]
+    fn truncated_stream_cannot_be_finished() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])
+                .unwrap();
+        let stream = write_stream(&schema, [&batch], IpcWriteOptions::default()).unwrap();
+
+        let mut decoder = IPCStreamDecoder::new();
+        decoder.push(&stream[..stream.len() - 3]).unwrap();
+        assert_eq!(decoder.num_pending_batches(), 1);
+        assert!(decoder.finish().is_err());
+    }
+}
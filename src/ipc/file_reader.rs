@@ -0,0 +1,208 @@
+use arrow_buffer::Buffer;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::error::WasmResult;
+use crate::ipc::footer::{block_range, footer_range, FileMetadata, TRAILER_LENGTH};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_ReadRange: &'static str = r#"
+export type ReadRange = (
+  offset: number,
+  length: number
+) => Promise<Uint8Array | ArrayBuffer>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ReadRange")]
+    pub type ReadRange;
+}
+
+/// Convert an exception thrown by the `readRange` callback to a [JsError].
+fn read_range_error(err: JsValue) -> JsError {
+    let message = match err.dyn_ref::<js_sys::Error>() {
+        Some(err) => String::from(err.message()),
+        None => format!("{err:?}"),
+    };
+    JsError::new(&format!("readRange failed: {message}"))
+}
+
+/// Call the `readRange` callback and check that it resolved to exactly `length` bytes.
+async fn read(read_range: &js_sys::Function, offset: u64, length: usize) -> WasmResult<Buffer> {
+    let promise = read_range
+        .call2(
+            &JsValue::NULL,
+            &JsValue::from_f64(offset as f64),
+            &JsValue::from_f64(length as f64),
+        )
+        .map_err(read_range_error)?;
+    let value = JsFuture::from(js_sys::Promise::resolve(&promise))
+        .await
+        .map_err(read_range_error)?;
+    let bytes = js_sys::Uint8Array::new(&value).to_vec();
+    if bytes.len() != length {
+        return Err(JsError::new(&format!(
+            "readRange returned {} bytes, expected {length}",
+            bytes.len()
+        )));
+    }
+    Ok(Buffer::from_vec(bytes))
+}
+
+/// A random-access reader for an Arrow IPC File that is not held in WebAssembly memory.
+///
+/// Bytes are fetched on demand through a `readRange(offset, length)` callback, for example with
+/// HTTP range requests. Opening the reader fetches only the footer and any dictionary batches;
+/// each {@linkcode RecordBatch} is then fetched when it is requested.
+///
+/// **Example**:
+///
+/// ```ts
+/// const readRange = async (offset: number, length: number) => {
+///   const response = await fetch(url, {
+///     headers: { Range: `bytes=${offset}-${offset + length - 1}` },
+///   });
+///   return new Uint8Array(await response.arrayBuffer());
+/// };
+/// const reader = await IPCFileReader.open(fileSize, readRange);
+/// const lastBatch = await reader.recordBatch(reader.numBatches - 1);
+/// ```
+#[wasm_bindgen]
+pub struct IPCFileReader {
+    read_range: js_sys::Function,
+    metadata: FileMetadata,
+}
+
+#[wasm_bindgen]
+impl IPCFileReader {
+    /// Open an Arrow IPC File by reading its footer and dictionary batches.
+    ///
+    /// @param fileSize the total size of the file in bytes.
+    /// @param readRange a callback that resolves to the `length` bytes of the file starting at
+    /// `offset`.
+    #[wasm_bindgen]
+    pub async fn open(file_size: f64, read_range: ReadRange) -> WasmResult<IPCFileReader> {
+        let read_range: js_sys::Function = read_range
+            .dyn_into()
+            .map_err(|_| JsError::new("readRange must be a function"))?;
+        let file_size = file_size as u64;
+        if file_size < TRAILER_LENGTH as u64 {
+            return Err(JsError::new("File is too small to be an Arrow IPC File"));
+        }
+
+        let trailer = read(
+            &read_range,
+            file_size - TRAILER_LENGTH as u64,
+            TRAILER_LENGTH,
+        )
+        .await?;
+        let (footer_offset, footer_length) = footer_range(file_size, &trailer)?;
+        let footer = read(&read_range, footer_offset, footer_length).await?;
+        let mut metadata = FileMetadata::try_new(&footer)?;
+
+        // Dictionaries are needed to decode any record batch, so load them all up front
+        for block in metadata.dictionaries.clone() {
+            let (offset, length) = block_range(&block);
+            let buf = read(&read_range, offset, length).await?;
+            metadata.decoder.read_dictionary(&block, &buf)?;
+        }
+
+        Ok(Self {
+            read_range,
+            metadata,
+        })
+    }
+
+    /// The schema of the file.
+    #[cfg(feature = "schema")]
+    #[wasm_bindgen(getter)]
+    pub fn schema(&self) -> crate::Schema {
+        crate::Schema::new(self.metadata.schema.clone())
+    }
+
+    /// The number of record batches in the file.
+    #[wasm_bindgen(getter, js_name = numBatches)]
+    pub fn num_batches(&self) -> usize {
+        self.metadata.batches.len()
+    }
+
+    /// Fetch and decode a single RecordBatch from the file.
+    ///
+    /// @param index The positional index of the RecordBatch to read.
+    #[wasm_bindgen(js_name = recordBatch)]
+    pub async fn record_batch(&self, index: usize) -> WasmResult<crate::RecordBatch> {
+        let block = self.metadata.batch_block(index)?;
+        let (offset, length) = block_range(&block);
+        let buf = read(&self.read_range, offset, length).await?;
+        let batch = self.metadata.read_record_batch(&block, &buf)?;
+        Ok(crate::RecordBatch::new(batch))
+    }
+
+    /// Fetch and decode every RecordBatch in the file into a {@linkcode Table}.
+    #[wasm_bindgen(js_name = readTable)]
+    pub async fn read_table(&self) -> WasmResult<crate::Table> {
+        let mut batches = Vec::with_capacity(self.metadata.batches.len());
+        for block in &self.metadata.batches {
+            let (offset, length) = block_range(block);
+            let buf = read(&self.read_range, offset, length).await?;
+            batches.push(self.metadata.read_record_batch(block, &buf)?);
+        }
+        Ok(crate::Table::new(self.metadata.schema.clone(), batches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int32Array, RecordBatch};
+    use arrow_ipc::writer::IpcWriteOptions;
+
+    use super::*;
+    use crate::ipc::write_file;
+
+    #[test]
+    fn read_file_by_ranges() {
+        let batches = (0..3)
+            .map(|i| {
+                let dictionary: DictionaryArray<Int32Type> =
+                    vec!["a", "b", "a"].into_iter().collect();
+                RecordBatch::try_from_iter([
+                    ("a", Arc::new(Int32Array::from(vec![i, i + 1, i + 2])) as _),
+                    ("b", Arc::new(dictionary) as _),
+                ])
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let schema = batches[0].schema();
+        let file = write_file(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let read = |(offset, length): (u64, usize)| {
+            Buffer::from_slice_ref(&file[offset as usize..offset as usize + length])
+        };
+
+        let file_size = file.len() as u64;
+        let trailer = read((file_size - TRAILER_LENGTH as u64, TRAILER_LENGTH));
+        let footer = read(footer_range(file_size, &trailer).unwrap());
+        let mut metadata = FileMetadata::try_new(&footer).unwrap();
+        assert_eq!(metadata.schema, schema);
+        assert_eq!(metadata.batches.len(), 3);
+        assert_eq!(metadata.dictionaries.len(), 1);
+
+        for block in metadata.dictionaries.clone() {
+            let buf = read(block_range(&block));
+            metadata.decoder.read_dictionary(&block, &buf).unwrap();
+        }
+
+        // Read the batches out of order
+        for index in [2, 0, 1] {
+            let block = metadata.batch_block(index).unwrap();
+            let buf = read(block_range(&block));
+            let batch = metadata.read_record_batch(&block, &buf).unwrap();
+            assert_eq!(batch, batches[index]);
+        }
+        assert!(metadata.batch_block(3).is_err());
+    }
+}
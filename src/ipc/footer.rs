@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_buffer::Buffer;
+use arrow_ipc::convert::fb_to_schema;
+use arrow_ipc::reader::{read_footer_length, FileDecoder};
+use arrow_ipc::{root_as_footer, Block};
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::error::Result;
+
+/// The length of the trailer at the end of every IPC File: a 4-byte footer length followed by the
+/// 6-byte magic.
+pub(crate) const TRAILER_LENGTH: usize = 10;
+
+/// Returns the byte range of the footer of an IPC File of `file_size` bytes, given its trailer.
+pub(crate) fn footer_range(file_size: u64, trailer: &[u8]) -> Result<(u64, usize)> {
+    let trailer: [u8; TRAILER_LENGTH] = trailer
+        .try_into()
+        .map_err(|_| ArrowError::ParseError("Invalid IPC File trailer".to_string()))?;
+    let footer_length = read_footer_length(trailer)?;
+    let footer_end = file_size - TRAILER_LENGTH as u64;
+    let footer_start = footer_end
+        .checked_sub(footer_length as u64)
+        .ok_or_else(|| ArrowError::ParseError(format!("Invalid footer length: {footer_length}")))?;
+    Ok((footer_start, footer_length))
+}
+
+/// Returns the byte range of the message stored in `block`.
+pub(crate) fn block_range(block: &Block) -> (u64, usize) {
+    let length = block.metaDataLength() as usize + block.bodyLength() as usize;
+    (block.offset() as u64, length)
+}
+
+/// The contents of the footer of an IPC File.
+pub(crate) struct FileMetadata {
+    pub(crate) schema: SchemaRef,
+    pub(crate) decoder: FileDecoder,
+    pub(crate) dictionaries: Vec<Block>,
+    pub(crate) batches: Vec<Block>,
+}
+
+impl FileMetadata {
+    pub(crate) fn try_new(footer: &[u8]) -> Result<Self> {
+        let footer = root_as_footer(footer).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IpcError("Unable to get schema from IPC footer".to_string())
+        })?;
+        let schema = Arc::new(fb_to_schema(ipc_schema));
+        let decoder = FileDecoder::new(schema.clone(), footer.version());
+        let dictionaries = footer
+            .dictionaries()
+            .map(|blocks| blocks.iter().copied().collect())
+            .unwrap_or_default();
+        let batches = footer
+            .recordBatches()
+            .map(|blocks| blocks.iter().copied().collect())
+            .unwrap_or_default();
+        Ok(Self {
+            schema,
+            decoder,
+            dictionaries,
+            batches,
+        })
+    }
+
+    /// Returns the block of the record batch at `index`.
+    #[cfg(feature = "record_batch")]
+    pub(crate) fn batch_block(&self, index: usize) -> Result<Block> {
+        self.batches.get(index).copied().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Record batch index {index} out of range for IPC File with {} batches",
+                self.batches.len()
+            ))
+            .into()
+        })
+    }
+
+    pub(crate) fn read_record_batch(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+    ) -> std::result::Result<RecordBatch, ArrowError> {
+        self.decoder
+            .read_record_batch(block, buf)?
+            .ok_or_else(|| ArrowError::IpcError("Empty record batch message".to_string()))
+    }
+}
@@ -0,0 +1,701 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::thread::LocalKey;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_buffer::Buffer;
+use arrow_ipc::convert::fb_to_schema;
+use arrow_ipc::reader::{read_dictionary, read_record_batch};
+use arrow_ipc::writer::{
+    write_message, CompressionContext, DictionaryTracker, IpcDataGenerator, IpcWriteOptions,
+};
+use arrow_ipc::{root_as_message, Message, MessageHeader};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use wasm_bindgen::prelude::*;
+
+use crate::error::Result;
+#[cfg(feature = "record_batch")]
+use crate::ipc::options::{IPCWriteOptions, WriteOptions};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_IPCDecodeOptions: &'static str = r#"
+export type IPCDecodeOptions = {
+  dictionaryMemo?: IPCDictionaryMemo;
+};
+"#;
+
+#[cfg(feature = "record_batch")]
+#[wasm_bindgen(typescript_custom_section)]
+const TS_IPCMessageOptions: &'static str = r#"
+export type IPCMessageOptions = IPCWriteOptions & {
+  dictionaryTracker?: IPCDictionaryTracker;
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "IPCDecodeOptions")]
+    pub type IPCDecodeOptions;
+}
+
+#[cfg(feature = "record_batch")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "IPCMessageOptions")]
+    pub type IPCMessageOptions;
+}
+
+/// The marker that starts every encapsulated IPC message, except in the legacy format.
+pub(crate) const CONTINUATION_MARKER: u32 = 0xFFFF_FFFF;
+
+/// Create a dictionary tracker that has not yet seen any dictionary of `schema`.
+///
+/// This assigns dictionary IDs to the dictionary fields of `schema` in the same order as an IPC
+/// writer would, which [encode_batch] relies on.
+pub fn dictionary_tracker(schema: &Schema) -> DictionaryTracker {
+    let mut tracker = DictionaryTracker::new(false);
+    IpcDataGenerator::default().schema_to_bytes_with_dictionary_tracker(
+        schema,
+        &mut tracker,
+        &IpcWriteOptions::default(),
+    );
+    tracker
+}
+
+/// Encode a schema as a single encapsulated IPC message.
+pub fn encode_schema(schema: &Schema, options: &IpcWriteOptions) -> Result<Vec<u8>> {
+    let mut tracker = DictionaryTracker::new(false);
+    let encoded = IpcDataGenerator::default().schema_to_bytes_with_dictionary_tracker(
+        schema,
+        &mut tracker,
+        options,
+    );
+    let mut output = Vec::new();
+    write_message(&mut output, encoded, options)?;
+    Ok(output)
+}
+
+/// Encode a record batch as encapsulated IPC messages: a dictionary batch message for every
+/// dictionary that `tracker` has not seen yet, followed by the record batch message.
+///
+/// `tracker` must have been created with [dictionary_tracker] for the schema of `batch`.
+pub fn encode_batch(
+    batch: &RecordBatch,
+    tracker: &mut DictionaryTracker,
+    options: &IpcWriteOptions,
+) -> Result<Vec<u8>> {
+    let (dictionaries, encoded) = IpcDataGenerator::default().encode(
+        batch,
+        tracker,
+        options,
+        &mut CompressionContext::default(),
+    )?;
+    let mut output = Vec::new();
+    for dictionary in dictionaries {
+        write_message(&mut output, dictionary, options)?;
+    }
+    write_message(&mut output, encoded, options)?;
+    Ok(output)
+}
+
+/// Returns `schema` with the dictionary IDs that an IPC writer assigns to its dictionary fields,
+/// so that dictionary batch messages can be matched to their fields.
+fn with_dict_ids(schema: &Schema) -> Result<SchemaRef> {
+    let encoded = IpcDataGenerator::default().schema_to_bytes_with_dictionary_tracker(
+        schema,
+        &mut DictionaryTracker::new(false),
+        &IpcWriteOptions::default(),
+    );
+    let message = root_as_message(&encoded.ipc_message)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))?;
+    let ipc_schema = message
+        .header_as_schema()
+        .ok_or_else(|| ArrowError::IpcError("Unable to read schema message".to_string()))?;
+    Ok(fb_to_schema(ipc_schema).into())
+}
+
+/// Read a little-endian `u32` at `offset`, or return an error if `buf` is too short.
+pub(crate) fn read_u32(buf: &[u8], offset: usize) -> Result<u32> {
+    let bytes = offset
+        .checked_add(4)
+        .and_then(|end| buf.get(offset..end))
+        .ok_or_else(|| ArrowError::IpcError("Unexpected end of IPC message".to_string()))?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Add two offsets or lengths of an IPC message, returning an error instead of overflowing.
+///
+/// The lengths come from untrusted message headers, so a wrapped sum could otherwise pass a
+/// bounds check or stop the reader from advancing.
+pub(crate) fn add_lengths(a: usize, b: usize) -> Result<usize> {
+    Ok(a.checked_add(b)
+        .ok_or_else(|| ArrowError::IpcError("IPC message length overflows".to_string()))?)
+}
+
+/// The body length of `message`, or an error if it is negative or does not fit in a `usize`.
+pub(crate) fn body_length(message: &Message) -> Result<usize> {
+    let body_length = message.bodyLength();
+    Ok(usize::try_from(body_length).map_err(|_| {
+        ArrowError::IpcError(format!("Invalid IPC message body length {body_length}"))
+    })?)
+}
+
+/// A single decoded IPC message.
+#[derive(Debug)]
+pub struct DecodedMessage {
+    pub header_type: &'static str,
+    pub body_length: usize,
+    pub schema: Option<SchemaRef>,
+    pub batch: Option<RecordBatch>,
+}
+
+/// Decode the encapsulated IPC messages in `buf`: any number of dictionary batch messages,
+/// optionally followed by a single schema or record batch message. The last message is returned.
+///
+/// Dictionary batch messages are stored in `dictionaries`, so that they can be used by record
+/// batch messages in `buf` or in later calls. Returns an error if a message follows a schema or
+/// record batch message.
+pub fn decode_messages(
+    buf: &[u8],
+    schema: &Schema,
+    dictionaries: &mut HashMap<i64, ArrayRef>,
+) -> Result<DecodedMessage> {
+    let schema = with_dict_ids(schema)?;
+    let mut buf = Buffer::from_slice_ref(buf);
+    let mut last = None;
+    while !buf.is_empty() {
+        let (prefix_length, metadata_length) = match read_u32(&buf, 0)? {
+            CONTINUATION_MARKER => (8, read_u32(&buf, 4)? as usize),
+            // The legacy format has no continuation marker
+            metadata_length => (4, metadata_length as usize),
+        };
+        if metadata_length == 0 {
+            // End-of-stream marker
+            break;
+        }
+        if matches!(&last, Some(DecodedMessage { header_type, .. }) if *header_type != "DictionaryBatch")
+        {
+            return Err(ArrowError::IpcError(
+                "Expected a single schema or record batch message, found more than one".to_string(),
+            )
+            .into());
+        }
+
+        let body_offset = add_lengths(prefix_length, metadata_length)?;
+        let metadata = buf
+            .get(prefix_length..body_offset)
+            .ok_or_else(|| ArrowError::IpcError("Unexpected end of IPC message".to_string()))?;
+        let message = root_as_message(metadata).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+        let body_length = body_length(&message)?;
+        let message_length = add_lengths(body_offset, body_length)?;
+        if buf.len() < message_length {
+            return Err(ArrowError::IpcError("Unexpected end of IPC message".to_string()).into());
+        }
+        let body = buf.slice_with_length(body_offset, body_length);
+
+        let mut decoded = DecodedMessage {
+            header_type: message.header_type().variant_name().unwrap_or("UNKNOWN"),
+            body_length,
+            schema: None,
+            batch: None,
+        };
+        match message.header_type() {
+            MessageHeader::Schema => {
+                let ipc_schema = message.header_as_schema().unwrap();
+                decoded.schema = Some(fb_to_schema(ipc_schema).into());
+            }
+            MessageHeader::DictionaryBatch => {
+                let dictionary = message.header_as_dictionary_batch().unwrap();
+                read_dictionary(&body, dictionary, &schema, dictionaries, &message.version())?;
+            }
+            MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().unwrap();
+                decoded.batch = Some(read_record_batch(
+                    &body,
+                    batch,
+                    schema.clone(),
+                    dictionaries,
+                    None,
+                    &message.version(),
+                )?);
+            }
+            t => {
+                return Err(
+                    ArrowError::IpcError(format!("Unsupported IPC message type: {t:?}")).into(),
+                )
+            }
+        }
+
+        last = Some(decoded);
+        buf.advance(message_length);
+    }
+
+    Ok(last.ok_or_else(|| ArrowError::IpcError("No IPC message to decode".to_string()))?)
+}
+
+/// The state of the objects that JS passes back in an options object.
+///
+/// wasm-bindgen can only borrow an exported object when it is passed as an argument of its own,
+/// so these objects keep their state here. Rust finds it again by `id`, while JS identifies an
+/// object by an opaque token, so that a live object cannot be forged from a plain number.
+struct Handles<T> {
+    next_id: usize,
+    entries: HashMap<usize, Handle<T>>,
+}
+
+struct Handle<T> {
+    value: T,
+    /// The token returned by the `handle` getter of the object, created on first use.
+    token: Option<js_sys::Object>,
+}
+
+impl<T> Default for Handles<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            entries: HashMap::new(),
+        }
+    }
+}
+
+type HandleStore<T> = LocalKey<RefCell<Handles<T>>>;
+
+thread_local! {
+    #[cfg(feature = "record_batch")]
+    static TRACKERS: RefCell<Handles<DictionaryTracker>> = RefCell::new(Handles::default());
+    static MEMOS: RefCell<Handles<HashMap<i64, ArrayRef>>> = RefCell::new(Handles::default());
+}
+
+/// Store `value`, returning the id to look it up with.
+fn insert_handle<T>(store: &'static HandleStore<T>, value: T) -> usize {
+    store.with(|handles| {
+        let mut handles = handles.borrow_mut();
+        let id = handles.next_id;
+        handles.next_id += 1;
+        handles.entries.insert(id, Handle { value, token: None });
+        id
+    })
+}
+
+/// Remove the value stored under `id`, if any.
+fn remove_handle<T>(store: &'static HandleStore<T>, id: usize) {
+    // The store may already be destroyed if this is called during thread teardown
+    let _ = store.try_with(|handles| handles.borrow_mut().entries.remove(&id));
+}
+
+/// Call `f` with the value stored under `id`, or return an error if there is none.
+#[cfg(feature = "record_batch")]
+fn with_handle<T, R>(
+    store: &'static HandleStore<T>,
+    id: usize,
+    f: impl FnOnce(&mut T) -> Result<R>,
+) -> Result<R> {
+    store.with(|handles| {
+        let mut handles = handles.borrow_mut();
+        let handle = handles.entries.get_mut(&id).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("No live object with id {id}"))
+        })?;
+        f(&mut handle.value)
+    })
+}
+
+/// Returns the token that identifies the object stored under `id` to JS.
+fn handle_token<T>(store: &'static HandleStore<T>, id: usize) -> JsValue {
+    store.with(|handles| {
+        let mut handles = handles.borrow_mut();
+        match handles.entries.get_mut(&id) {
+            Some(handle) => handle
+                .token
+                .get_or_insert_with(js_sys::Object::new)
+                .clone()
+                .into(),
+            None => JsValue::UNDEFINED,
+        }
+    })
+}
+
+/// Call `f` with the value of the object under `key` in a JS options object, or with `None` if
+/// the option is unset.
+///
+/// The object is looked up by the token of its `handle` getter, so anything but a live object of
+/// the type kept in `store` is rejected.
+#[cfg(any(feature = "schema", feature = "record_batch"))]
+fn with_option_handle<T, R>(
+    store: &'static HandleStore<T>,
+    options: Option<&JsValue>,
+    key: &str,
+    f: impl FnOnce(Option<&mut T>) -> Result<R>,
+) -> crate::error::WasmResult<R> {
+    let invalid = || {
+        ArrowError::InvalidArgumentError(format!(
+            "The `{key}` option is not a live object of the expected type"
+        ))
+    };
+    let value = match options {
+        Some(options) => {
+            js_sys::Reflect::get(options, &JsValue::from_str(key)).map_err(|_| invalid())?
+        }
+        None => JsValue::UNDEFINED,
+    };
+    if value.is_undefined() || value.is_null() {
+        return Ok(f(None)?);
+    }
+    let token =
+        js_sys::Reflect::get(&value, &JsValue::from_str("handle")).map_err(|_| invalid())?;
+    store.with(|handles| {
+        let mut handles = handles.borrow_mut();
+        let handle = handles
+            .entries
+            .values_mut()
+            .find(|handle| {
+                handle
+                    .token
+                    .as_ref()
+                    .map_or(false, |known| js_sys::Object::is(known, &token))
+            })
+            .ok_or_else(invalid)?;
+        Ok(f(Some(&mut handle.value))?)
+    })
+}
+
+/// Tracks the dictionaries that have already been encoded by {@linkcode RecordBatch.toIPCMessage},
+/// so that each dictionary is only sent once over a custom transport.
+///
+/// A tracker belongs to a single schema. Every RecordBatch encoded with it must conform to that
+/// schema.
+#[cfg(feature = "record_batch")]
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct IPCDictionaryTracker {
+    id: usize,
+}
+
+#[cfg(feature = "record_batch")]
+impl IPCDictionaryTracker {
+    /// Create a tracker that has not yet seen any dictionary of `schema`.
+    pub fn from_schema(schema: &Schema) -> Self {
+        Self {
+            id: insert_handle(&TRACKERS, dictionary_tracker(schema)),
+        }
+    }
+
+    /// Encode `batch` like [encode_batch], only including the dictionaries that this tracker has
+    /// not seen yet.
+    pub fn encode(&self, batch: &RecordBatch, options: &IpcWriteOptions) -> Result<Vec<u8>> {
+        with_handle(&TRACKERS, self.id, |tracker| {
+            encode_batch(batch, tracker, options)
+        })
+    }
+}
+
+#[cfg(feature = "record_batch")]
+impl Drop for IPCDictionaryTracker {
+    fn drop(&mut self) {
+        remove_handle(&TRACKERS, self.id);
+    }
+}
+
+#[cfg(feature = "record_batch")]
+#[wasm_bindgen]
+impl IPCDictionaryTracker {
+    /// Create a tracker for the given schema.
+    #[cfg(feature = "schema")]
+    #[wasm_bindgen(constructor)]
+    pub fn new(schema: &crate::Schema) -> Self {
+        Self::from_schema(&schema.0)
+    }
+
+    /// An opaque token that identifies this tracker when it is passed in an options object.
+    #[wasm_bindgen(getter)]
+    pub fn handle(&self) -> JsValue {
+        handle_token(&TRACKERS, self.id)
+    }
+}
+
+/// Encode `batch` as encapsulated IPC messages with the options of {@linkcode
+/// RecordBatch.toIPCMessage}.
+///
+/// Every dictionary of `batch` is included unless `options` holds a `dictionaryTracker`.
+#[cfg(feature = "record_batch")]
+pub fn encode_batch_message(
+    batch: &RecordBatch,
+    options: Option<IPCMessageOptions>,
+) -> crate::error::WasmResult<Vec<u8>> {
+    let options = options.map(JsValue::from);
+    // The write options are read from the same object, ignoring `dictionaryTracker`
+    let write_options = WriteOptions::from_js(
+        options
+            .clone()
+            .map(JsCast::unchecked_into::<IPCWriteOptions>),
+    )?;
+    let write_options = (&write_options).try_into()?;
+    with_option_handle(
+        &TRACKERS,
+        options.as_ref(),
+        "dictionaryTracker",
+        |tracker| match tracker {
+            Some(tracker) => encode_batch(batch, tracker, &write_options),
+            None => encode_batch(
+                batch,
+                &mut dictionary_tracker(batch.schema_ref()),
+                &write_options,
+            ),
+        },
+    )
+}
+
+/// Stores the dictionaries decoded from IPC messages, so that record batch messages can refer to
+/// dictionaries that were sent in earlier messages.
+///
+/// Pass the memo as the `dictionaryMemo` option of {@linkcode decodeIPCMessage}.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct IPCDictionaryMemo {
+    id: usize,
+}
+
+impl Default for IPCDictionaryMemo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for IPCDictionaryMemo {
+    fn drop(&mut self) {
+        remove_handle(&MEMOS, self.id);
+    }
+}
+
+#[wasm_bindgen]
+impl IPCDictionaryMemo {
+    /// Create an empty dictionary memo.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            id: insert_handle(&MEMOS, HashMap::new()),
+        }
+    }
+
+    /// An opaque token that identifies this memo when it is passed in an options object.
+    #[wasm_bindgen(getter)]
+    pub fn handle(&self) -> JsValue {
+        handle_token(&MEMOS, self.id)
+    }
+
+    /// The number of dictionaries stored in this memo.
+    #[wasm_bindgen(getter, js_name = numDictionaries)]
+    pub fn num_dictionaries(&self) -> usize {
+        MEMOS.with(|handles| {
+            handles
+                .borrow()
+                .entries
+                .get(&self.id)
+                .map_or(0, |handle| handle.value.len())
+        })
+    }
+}
+
+/// An IPC message decoded by {@linkcode decodeIPCMessage}.
+#[wasm_bindgen]
+pub struct IPCMessage(DecodedMessage);
+
+#[wasm_bindgen]
+impl IPCMessage {
+    /// The type of the message header, one of `"Schema"`, `"DictionaryBatch"` or
+    /// `"RecordBatch"`.
+    #[wasm_bindgen(getter, js_name = headerType)]
+    pub fn header_type(&self) -> String {
+        self.0.header_type.to_string()
+    }
+
+    /// The length in bytes of the message body.
+    #[wasm_bindgen(getter, js_name = bodyLength)]
+    pub fn body_length(&self) -> usize {
+        self.0.body_length
+    }
+
+    /// The decoded schema if this is a schema message, else `null`.
+    #[cfg(feature = "schema")]
+    #[wasm_bindgen(getter)]
+    pub fn schema(&self) -> Option<crate::Schema> {
+        self.0.schema.clone().map(crate::Schema::new)
+    }
+
+    /// The decoded RecordBatch if this is a record batch message, else `null`.
+    #[cfg(feature = "record_batch")]
+    #[wasm_bindgen(getter, js_name = recordBatch)]
+    pub fn record_batch(&self) -> Option<crate::RecordBatch> {
+        self.0.batch.clone().map(crate::RecordBatch::new)
+    }
+}
+
+/// Decode encapsulated IPC messages, such as those produced by {@linkcode Schema.toIPCMessage}
+/// and {@linkcode RecordBatch.toIPCMessage}.
+///
+/// `bytes` may hold any number of dictionary batch messages, followed by a single schema or
+/// record batch message, which is returned. Record batch messages can only refer to dictionaries
+/// sent earlier in `bytes`, unless a `dictionaryMemo` is given to keep dictionaries across calls.
+///
+/// @param bytes the encapsulated IPC messages.
+/// @param schema the schema of the record batches being decoded.
+/// @param options an optional `dictionaryMemo` holding the dictionaries of earlier messages
+#[cfg(feature = "schema")]
+#[wasm_bindgen(js_name = decodeIPCMessage)]
+pub fn decode_ipc_message(
+    bytes: &[u8],
+    schema: &crate::Schema,
+    options: Option<IPCDecodeOptions>,
+) -> crate::error::WasmResult<IPCMessage> {
+    let options = options.map(JsValue::from);
+    let decoded = with_option_handle(&MEMOS, options.as_ref(), "dictionaryMemo", |dictionaries| {
+        match dictionaries {
+            Some(dictionaries) => decode_messages(bytes, &schema.0, dictionaries),
+            None => decode_messages(bytes, &schema.0, &mut HashMap::new()),
+        }
+    })?;
+    Ok(IPCMessage(decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int32Array};
+
+    use super::*;
+    use crate::test_util::batch_message_with_body_length;
+
+    #[test]
+    fn message_round_trip() {
+        let batch = |values: Vec<&'static str>| {
+            let dictionary: DictionaryArray<Int32Type> = values.into_iter().collect();
+            RecordBatch::try_from_iter([
+                ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+                ("b", Arc::new(dictionary.clone()) as ArrayRef),
+                ("c", Arc::new(dictionary) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        let first = batch(vec!["x", "y"]);
+        let schema = first.schema();
+        let options = IpcWriteOptions::default();
+        let mut dictionaries = HashMap::new();
+
+        let message = encode_schema(&schema, &options).unwrap();
+        let decoded = decode_messages(&message, &schema, &mut dictionaries).unwrap();
+        assert_eq!(decoded.header_type, "Schema");
+        assert_eq!(decoded.body_length, 0);
+        assert_eq!(decoded.schema, Some(schema.clone()));
+
+        // The first message of a tracker includes both dictionaries
+        let mut tracker = dictionary_tracker(&schema);
+        let message = encode_batch(&first, &mut tracker, &options).unwrap();
+        let decoded = decode_messages(&message, &schema, &mut dictionaries).unwrap();
+        assert_eq!(decoded.header_type, "RecordBatch");
+        assert!(decoded.body_length > 0);
+        assert_eq!(decoded.batch, Some(first.clone()));
+        assert_eq!(dictionaries.len(), 2);
+
+        // Later messages reuse the dictionaries that have already been sent
+        let with_dictionaries = message.len();
+        let message = encode_batch(&first, &mut tracker, &options).unwrap();
+        assert!(message.len() < with_dictionaries);
+        let decoded = decode_messages(&message, &schema, &mut dictionaries).unwrap();
+        assert_eq!(decoded.batch, Some(first));
+
+        // A new dictionary is sent again
+        let second = batch(vec!["z", "x"]);
+        let message = encode_batch(&second, &mut tracker, &options).unwrap();
+        let decoded = decode_messages(&message, &schema, &mut dictionaries).unwrap();
+        assert_eq!(decoded.batch, Some(second.clone()));
+
+        // Only dictionary batch messages may precede the returned message
+        let mut messages = encode_schema(&schema, &options).unwrap();
+        messages.extend(encode_batch(&second, &mut dictionary_tracker(&schema), &options).unwrap());
+        assert!(decode_messages(&messages, &schema, &mut dictionaries).is_err());
+        let mut messages = message.clone();
+        messages.extend_from_slice(&message);
+        assert!(decode_messages(&messages, &schema, &mut dictionaries).is_err());
+
+        assert!(decode_messages(&[], &schema, &mut dictionaries).is_err());
+        assert!(
+            decode_messages(&message[..message.len() - 1], &schema, &mut dictionaries).is_err()
+        );
+    }
+
+    #[test]
+    fn reject_invalid_body_length() {
+        for body_length in [-1, i64::MIN, i64::MAX, u32::MAX as i64 + 1] {
+            let (schema, message) = batch_message_with_body_length(body_length);
+            assert!(decode_messages(&message, &schema, &mut HashMap::new()).is_err());
+        }
+    }
+
+    #[cfg(feature = "record_batch")]
+    #[test]
+    fn tracker_is_removed_when_dropped() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(DictionaryArray::<Int32Type>::from_iter(["x", "y"])) as ArrayRef,
+        )])
+        .unwrap();
+        let options = IpcWriteOptions::default();
+        let num_trackers = || TRACKERS.with(|handles| handles.borrow().entries.len());
+
+        let tracker = IPCDictionaryTracker::from_schema(batch.schema_ref());
+        let other = IPCDictionaryTracker::from_schema(batch.schema_ref());
+        assert_ne!(tracker.id, other.id);
+        assert_eq!(num_trackers(), 2);
+
+        // Each tracker keeps its own record of the dictionaries it has sent
+        let with_dictionaries = tracker.encode(&batch, &options).unwrap();
+        assert!(tracker.encode(&batch, &options).unwrap().len() < with_dictionaries.len());
+        assert_eq!(other.encode(&batch, &options).unwrap(), with_dictionaries);
+
+        let id = tracker.id;
+        drop(tracker);
+        assert_eq!(num_trackers(), 1);
+        assert!(with_handle(&TRACKERS, id, |_| Ok(())).is_err());
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32", feature = "schema"))]
+mod wasm_tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::test_util::three_batch_table;
+
+    /// Build the options object `{ dictionaryMemo: memo }`.
+    fn decode_options(memo: &JsValue) -> IPCDecodeOptions {
+        let options = js_sys::Object::new();
+        js_sys::Reflect::set(&options, &JsValue::from_str("dictionaryMemo"), memo).unwrap();
+        options.unchecked_into()
+    }
+
+    #[wasm_bindgen_test]
+    fn decode_with_dictionary_memo() {
+        let (schema, batches) = three_batch_table();
+        let mut tracker = dictionary_tracker(&schema);
+        let message = encode_batch(&batches[0], &mut tracker, &IpcWriteOptions::default()).unwrap();
+        let schema = crate::Schema::new(schema);
+
+        let memo = JsValue::from(IPCDictionaryMemo::new());
+        let options = decode_options(&memo);
+        assert!(decode_ipc_message(&message, &schema, Some(options)).is_ok());
+
+        // A plain object that imitates a memo is rejected
+        let forged = js_sys::Object::new();
+        js_sys::Reflect::set(
+            &forged,
+            &JsValue::from_str("handle"),
+            &js_sys::Object::new(),
+        )
+        .unwrap();
+        let options = decode_options(&forged);
+        assert!(decode_ipc_message(&message, &schema, Some(options)).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+//! Helpers for reading and writing the Arrow IPC Stream and File formats.
+//!
+//! Compressed IPC bodies can only be written and read when the `ipc_compression` feature is
+//! enabled. Without it, writing with a compression codec or reading a compressed buffer fails at
+//! runtime.
+
+mod buffer;
+#[cfg(feature = "record_batch")]
+mod decoder;
+#[cfg(feature = "record_batch")]
+mod file_reader;
+mod footer;
+mod message;
+mod options;
+mod reader;
+#[cfg(all(feature = "record_batch", feature = "schema"))]
+mod stream_writer;
+mod writer;
+
+pub use buffer::{alloc_buffer, read_buffer, WasmBuffer};
+#[cfg(feature = "record_batch")]
+pub use decoder::IPCStreamDecoder;
+#[cfg(feature = "record_batch")]
+pub use file_reader::{IPCFileReader, ReadRange};
+#[cfg(feature = "schema")]
+pub use message::decode_ipc_message;
+pub use message::{
+    decode_messages, dictionary_tracker, encode_batch, encode_schema, DecodedMessage,
+    IPCDecodeOptions, IPCDictionaryMemo, IPCMessage,
+};
+#[cfg(feature = "record_batch")]
+pub use message::{encode_batch_message, IPCDictionaryTracker, IPCMessageOptions};
+pub use options::{
+    IPCCompression, IPCMetadataVersion, IPCReadOptions, IPCWriteOptions, ReadOptions, WriteOptions,
+};
+pub use reader::{is_ipc_file, read_file, read_stream, read_stream_batch, read_stream_schema};
+#[cfg(all(feature = "record_batch", feature = "schema"))]
+pub use stream_writer::IPCStreamWriter;
+pub use writer::{write_file, write_stream};
@@ -0,0 +1,180 @@
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_ipc::{CompressionType, MetadataVersion};
+use arrow_schema::ArrowError;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::column::{column_indices, ColumnSelector};
+use crate::error::{Result, WasmResult};
+use crate::validation::ValidationLevel;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_IPCWriteOptions: &'static str = r#"
+export type IPCCompression = "lz4_frame" | "zstd";
+
+export type IPCMetadataVersion = "V4" | "V5";
+
+export type IPCWriteOptions = {
+  compression?: IPCCompression | null;
+  alignment?: 8 | 16 | 32 | 64;
+  metadataVersion?: IPCMetadataVersion;
+};
+
+export type IPCReadOptions = {
+  columns?: ColumnSelector[] | null;
+  limit?: number | null;
+  offset?: number;
+  validation?: Validation;
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "IPCWriteOptions")]
+    pub type IPCWriteOptions;
+
+    #[wasm_bindgen(typescript_type = "IPCReadOptions")]
+    pub type IPCReadOptions;
+}
+
+/// The codec used to compress the buffers of each IPC message body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IPCCompression {
+    Lz4Frame,
+    Zstd,
+}
+
+impl From<IPCCompression> for CompressionType {
+    fn from(value: IPCCompression) -> Self {
+        match value {
+            IPCCompression::Lz4Frame => CompressionType::LZ4_FRAME,
+            IPCCompression::Zstd => CompressionType::ZSTD,
+        }
+    }
+}
+
+/// The version of the IPC metadata to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum IPCMetadataVersion {
+    V4,
+    V5,
+}
+
+impl From<IPCMetadataVersion> for MetadataVersion {
+    fn from(value: IPCMetadataVersion) -> Self {
+        match value {
+            IPCMetadataVersion::V4 => MetadataVersion::V4,
+            IPCMetadataVersion::V5 => MetadataVersion::V5,
+        }
+    }
+}
+
+/// Options for writing the Arrow IPC Stream and File formats.
+///
+/// Every field is optional and falls back to the default of [IpcWriteOptions]: no compression,
+/// 64-byte alignment and metadata version V5.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteOptions {
+    pub compression: Option<IPCCompression>,
+    pub alignment: Option<usize>,
+    pub metadata_version: Option<IPCMetadataVersion>,
+}
+
+impl WriteOptions {
+    /// Parse write options from a JS object, using the defaults if `options` is not provided.
+    pub fn from_js(options: Option<IPCWriteOptions>) -> WasmResult<Self> {
+        match options {
+            Some(options) => Ok(serde_wasm_bindgen::from_value(options.into())?),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+impl TryFrom<&WriteOptions> for IpcWriteOptions {
+    type Error = ArrowError;
+
+    fn try_from(value: &WriteOptions) -> std::result::Result<Self, Self::Error> {
+        // arrow-ipc panics when writing with a codec whose feature is disabled, so reject it here
+        #[cfg(not(feature = "ipc_compression"))]
+        if value.compression.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "IPC compression requires the ipc_compression feature".to_string(),
+            ));
+        }
+
+        let metadata_version = value
+            .metadata_version
+            .map_or(MetadataVersion::V5, Into::into);
+        IpcWriteOptions::try_new(value.alignment.unwrap_or(64), false, metadata_version)?
+            .try_with_compression(value.compression.map(Into::into))
+    }
+}
+
+/// Options for reading the Arrow IPC Stream and File formats.
+///
+/// `columns` selects the columns to decode, by name or by index, in the order they should appear
+/// in the output. `offset` rows are skipped and at most `limit` rows are returned; decoding stops
+/// as soon as the limit is met. `validation` controls how thoroughly each decoded batch is checked.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadOptions {
+    pub columns: Option<Vec<ColumnSelector>>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    #[serde(default)]
+    pub validation: ValidationLevel,
+}
+
+impl ReadOptions {
+    /// Parse read options from a JS object, using the defaults if `options` is not provided.
+    pub fn from_js(options: Option<IPCReadOptions>) -> WasmResult<Self> {
+        match options {
+            Some(options) => Ok(serde_wasm_bindgen::from_value(options.into())?),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the indices of the selected columns in `schema`, or `None` to read every column.
+    pub fn projection(&self, schema: &arrow_schema::Schema) -> Result<Option<Vec<usize>>> {
+        self.columns
+            .as_ref()
+            .map(|columns| column_indices(schema, columns))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_options_validation() {
+        assert!(IpcWriteOptions::try_from(&WriteOptions::default()).is_ok());
+
+        let options = WriteOptions {
+            alignment: Some(12),
+            ..Default::default()
+        };
+        assert!(IpcWriteOptions::try_from(&options).is_err());
+
+        // Compression requires metadata version V5
+        let options = WriteOptions {
+            compression: Some(IPCCompression::Zstd),
+            metadata_version: Some(IPCMetadataVersion::V4),
+            ..Default::default()
+        };
+        assert!(IpcWriteOptions::try_from(&options).is_err());
+    }
+
+    #[cfg(not(feature = "ipc_compression"))]
+    #[test]
+    fn compression_requires_feature() {
+        let options = WriteOptions {
+            compression: Some(IPCCompression::Lz4Frame),
+            ..Default::default()
+        };
+        assert!(IpcWriteOptions::try_from(&options).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use arrow_select::concat::concat_batches;
+
+use crate::error::Result;
+use crate::ipc::ReadOptions;
+
+/// Read an Arrow IPC Stream buffer into a schema and a sequence of record batches.
+pub fn read_stream(buf: &[u8], options: &ReadOptions) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    // The schema is needed to resolve column names, so peek at the schema message first
+    let schema = StreamReader::try_new(Cursor::new(buf), None)?.schema();
+    let projection = options.projection(&schema)?;
+    let reader = StreamReader::try_new(Cursor::new(buf), projection.clone())?;
+    // Safety: the batches are validated at the requested level by `read_batches`, so arrow-ipc
+    // does not need to validate them as well
+    let reader = unsafe { reader.with_skip_validation(true) };
+    read_batches(schema, projection, reader, options)
+}
+
+/// Read the schema message at the start of an Arrow IPC Stream buffer, ignoring any record
+/// batches that follow it.
+pub fn read_stream_schema(buf: &[u8]) -> Result<SchemaRef> {
+    Ok(StreamReader::try_new(Cursor::new(buf), None)?.schema())
+}
+
+/// Read an Arrow IPC Stream buffer that holds a single record batch.
+///
+/// If the stream holds more than one batch, they are concatenated when `concatenate` is `true`
+/// and an error is returned otherwise. A stream with no batches yields an empty batch.
+pub fn read_stream_batch(
+    buf: &[u8],
+    concatenate: bool,
+    options: &ReadOptions,
+) -> Result<RecordBatch> {
+    let (schema, batches) = read_stream(buf, options)?;
+    match batches.len() {
+        0 => Ok(RecordBatch::new_empty(schema)),
+        1 => Ok(batches.into_iter().next().unwrap()),
+        _ if concatenate => Ok(concat_batches(&schema, &batches)?),
+        num_batches => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected a single record batch in IPC Stream, found {num_batches}"
+        ))
+        .into()),
+    }
+}
+
+/// Read an Arrow IPC File buffer into a schema and a sequence of record batches.
+pub fn read_file(buf: &[u8], options: &ReadOptions) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let schema = FileReader::try_new(Cursor::new(buf), None)?.schema();
+    let projection = options.projection(&schema)?;
+    let reader = FileReader::try_new(Cursor::new(buf), projection.clone())?;
+    // Safety: the batches are validated at the requested level by `read_batches`, so arrow-ipc
+    // does not need to validate them as well
+    let reader = unsafe { reader.with_skip_validation(true) };
+    read_batches(schema, projection, reader, options)
+}
+
+/// The magic bytes at the start and end of every Arrow IPC File.
+const ARROW_FILE_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// Returns `true` if `buf` starts with the magic bytes of the Arrow IPC File format.
+pub fn is_ipc_file(buf: &[u8]) -> bool {
+    buf.starts_with(ARROW_FILE_MAGIC)
+}
+
+/// Collect the batches of `reader` that overlap the row range selected by `options`, slicing the
+/// first and last of them to fit.
+///
+/// Every batch is validated at the level of `options`, so the decoders feeding `reader` should
+/// skip their own validation.
+pub(crate) fn read_batches(
+    schema: SchemaRef,
+    projection: Option<Vec<usize>>,
+    reader: impl Iterator<Item = std::result::Result<RecordBatch, ArrowError>>,
+    options: &ReadOptions,
+) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    // The readers report the full schema even when reading a projection
+    let schema = match projection {
+        Some(projection) => Arc::new(schema.project(&projection)?),
+        None => schema,
+    };
+
+    let mut skip = options.offset.unwrap_or(0);
+    let mut remaining = options.limit.unwrap_or(usize::MAX);
+    let mut batches = vec![];
+    for batch in reader {
+        if remaining == 0 {
+            break;
+        }
+
+        let batch = batch?;
+        options.validation.validate_batch(&batch)?;
+        if skip >= batch.num_rows() {
+            skip -= batch.num_rows();
+            continue;
+        }
+        let length = (batch.num_rows() - skip).min(remaining);
+        batches.push(batch.slice(skip, length));
+        remaining -= length;
+        skip = 0;
+    }
+
+    Ok((schema, batches))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Array, Int32Array, StringArray};
+    use arrow_ipc::writer::IpcWriteOptions;
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::column::ColumnSelector;
+    use crate::ipc::{write_file, write_stream};
+    use crate::validation::ValidationLevel;
+
+    fn batches() -> (SchemaRef, Vec<RecordBatch>) {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", DataType::Int32, false),
+        ]));
+        let batches = (0..4)
+            .map(|i| {
+                let values = (i * 10..i * 10 + 10).collect::<Vec<_>>();
+                let strings = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int32Array::from(values.clone())),
+                        Arc::new(StringArray::from(strings)),
+                        Arc::new(Int32Array::from(values)),
+                    ],
+                )
+                .unwrap()
+            })
+            .collect();
+        (schema, batches)
+    }
+
+    #[test]
+    fn read_projection_and_rows() {
+        let (schema, batches) = batches();
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let file = write_file(&schema, &batches, IpcWriteOptions::default()).unwrap();
+
+        let options = ReadOptions {
+            columns: Some(vec![
+                ColumnSelector::Name("c".to_string()),
+                ColumnSelector::Index(1),
+            ]),
+            offset: Some(15),
+            limit: Some(10),
+            validation: ValidationLevel::Basic,
+        };
+        for (read_schema, read_batches) in [
+            read_stream(&stream, &options).unwrap(),
+            read_file(&file, &options).unwrap(),
+        ] {
+            assert_eq!(read_schema.field(0).name(), "c");
+            assert_eq!(read_schema.field(1).name(), "b");
+            assert_eq!(read_batches.len(), 2);
+            assert_eq!(read_batches[0].schema(), read_schema);
+            assert_eq!(read_batches[0].num_rows(), 5);
+            assert_eq!(read_batches[1].num_rows(), 5);
+
+            let first = read_batches[0].column(0);
+            let first = first.as_any().downcast_ref::<Int32Array>().unwrap();
+            assert_eq!(first.value(0), 15);
+        }
+    }
+
+    #[test]
+    fn read_schema_and_single_batch() {
+        let (schema, batches) = batches();
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        assert_eq!(read_stream_schema(&stream).unwrap(), schema);
+
+        assert!(read_stream_batch(&stream, false, &ReadOptions::default()).is_err());
+        let batch = read_stream_batch(&stream, true, &ReadOptions::default()).unwrap();
+        assert_eq!(batch, concat_batches(&schema, &batches).unwrap());
+
+        let stream = write_stream(&schema, &batches[..1], IpcWriteOptions::default()).unwrap();
+        assert_eq!(
+            read_stream_batch(&stream, false, &ReadOptions::default()).unwrap(),
+            batches[0]
+        );
+
+        let stream = write_stream(&schema, [], IpcWriteOptions::default()).unwrap();
+        assert_eq!(
+            read_stream_batch(&stream, false, &ReadOptions::default())
+                .unwrap()
+                .num_rows(),
+            0
+        );
+
+        // The read options are applied before the batches are concatenated
+        let options = ReadOptions {
+            offset: Some(5),
+            limit: Some(10),
+            ..Default::default()
+        };
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let batch = read_stream_batch(&stream, true, &options).unwrap();
+        assert_eq!(
+            batch,
+            concat_batches(&schema, &batches).unwrap().slice(5, 10)
+        );
+    }
+
+    #[test]
+    fn validate_at_requested_level() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(StringArray::from(vec!["wxyz"]))],
+        )
+        .unwrap();
+        let mut stream = write_stream(&schema, [&batch], IpcWriteOptions::default()).unwrap();
+        let start = stream.windows(4).position(|w| w == b"wxyz").unwrap();
+        stream[start] = 0xFF;
+
+        for (validation, valid) in [
+            (ValidationLevel::None, true),
+            (ValidationLevel::Basic, true),
+            (ValidationLevel::Full, false),
+        ] {
+            let options = ReadOptions {
+                validation,
+                ..Default::default()
+            };
+            assert_eq!(read_stream(&stream, &options).is_ok(), valid);
+        }
+    }
+
+    #[test]
+    fn read_everything_by_default() {
+        let (schema, batches) = batches();
+        let stream = write_stream(&schema, &batches, IpcWriteOptions::default()).unwrap();
+        let (read_schema, read_batches) = read_stream(&stream, &ReadOptions::default()).unwrap();
+        assert_eq!(read_schema, schema);
+        assert_eq!(read_batches, batches);
+
+        // An offset past the end returns no batches
+        let options = ReadOptions {
+            offset: Some(100),
+            ..Default::default()
+        };
+        let (_, read_batches) = read_stream(&stream, &options).unwrap();
+        assert!(read_batches.is_empty());
+    }
+}
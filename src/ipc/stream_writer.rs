@@ -0,0 +1,125 @@
+use arrow_ipc::writer::StreamWriter;
+use wasm_bindgen::prelude::*;
+
+use crate::error::{Result, WasmResult};
+use crate::ipc::{IPCWriteOptions, WriteOptions};
+
+/// An incremental writer for the Arrow IPC Stream format.
+///
+/// Each call to {@linkcode IPCStreamWriter.write} returns only the bytes produced since the
+/// previous call, so that a stream can be sent to a server or a `WritableStream` batch by batch
+/// without building a full {@linkcode Table} first. Concatenating the output of every `write`
+/// call followed by the output of {@linkcode IPCStreamWriter.finish} yields a complete IPC
+/// Stream.
+///
+/// **Example**:
+///
+/// ```ts
+/// const writer = new IPCStreamWriter(schema, { compression: "zstd" });
+/// for (const batch of batches) {
+///   await sink.write(writer.write(batch));
+/// }
+/// await sink.write(writer.finish());
+/// ```
+#[wasm_bindgen]
+pub struct IPCStreamWriter(StreamWriter<Vec<u8>>);
+
+impl IPCStreamWriter {
+    /// Create a writer for the given schema.
+    ///
+    /// The schema message is written immediately and returned by the first call to
+    /// [IPCStreamWriter::take_bytes].
+    pub fn try_new(
+        schema: &arrow_schema::Schema,
+        options: arrow_ipc::writer::IpcWriteOptions,
+    ) -> Result<Self> {
+        Ok(Self(StreamWriter::try_new_with_options(
+            Vec::new(),
+            schema,
+            options,
+        )?))
+    }
+
+    /// Write a record batch, keeping the encoded bytes in the internal buffer.
+    pub fn write_batch(&mut self, batch: &arrow_array::RecordBatch) -> Result<()> {
+        Ok(self.0.write(batch)?)
+    }
+
+    /// Take the bytes written since the last call out of the internal buffer.
+    pub fn take_bytes(&mut self) -> Vec<u8> {
+        std::mem::take(self.0.get_mut())
+    }
+}
+
+#[wasm_bindgen]
+impl IPCStreamWriter {
+    /// Create a writer for the given schema.
+    ///
+    /// @param schema the schema that every written RecordBatch must conform to.
+    /// @param options optional compression, alignment and metadata version of the output.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        schema: &crate::Schema,
+        options: Option<IPCWriteOptions>,
+    ) -> WasmResult<IPCStreamWriter> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(Self::try_new(&schema.0, (&options).try_into()?)?)
+    }
+
+    /// Write a RecordBatch to the stream.
+    ///
+    /// @returns the bytes produced since the previous call. The first call also includes the
+    /// schema message, and any call may include dictionary messages before the batch itself.
+    #[wasm_bindgen]
+    pub fn write(&mut self, batch: &crate::RecordBatch) -> WasmResult<Vec<u8>> {
+        self.write_batch(&batch.0)?;
+        Ok(self.take_bytes())
+    }
+
+    /// Write the end-of-stream marker.
+    ///
+    /// No more batches can be written after this call.
+    ///
+    /// @returns the bytes produced since the previous call, ending with the end-of-stream marker.
+    #[wasm_bindgen]
+    pub fn finish(&mut self) -> WasmResult<Vec<u8>> {
+        self.0.finish()?;
+        Ok(self.take_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use arrow_ipc::reader::StreamReader;
+    use arrow_ipc::writer::IpcWriteOptions;
+
+    use super::*;
+    use crate::test_util::three_batch_table;
+
+    #[test]
+    fn incremental_write() {
+        let (schema, batches) = three_batch_table();
+        let mut writer = IPCStreamWriter::try_new(&schema, IpcWriteOptions::default()).unwrap();
+
+        let mut output = writer.take_bytes();
+        assert!(!output.is_empty());
+
+        for batch in &batches {
+            writer.write_batch(batch).unwrap();
+            let bytes = writer.take_bytes();
+            assert!(!bytes.is_empty());
+            output.extend(bytes);
+        }
+        writer.0.finish().unwrap();
+        output.extend(writer.take_bytes());
+
+        let reader = StreamReader::try_new(Cursor::new(output), None).unwrap();
+        assert_eq!(reader.schema(), schema);
+        let decoded = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, batches);
+
+        assert!(writer.write_batch(&batches[0]).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use arrow_array::RecordBatch;
+use arrow_ipc::writer::{FileWriter, IpcWriteOptions, StreamWriter};
+use arrow_schema::Schema;
+
+use crate::error::Result;
+
+/// Write a schema and a sequence of record batches to an Arrow IPC Stream buffer.
+pub fn write_stream<'a>(
+    schema: &Schema,
+    batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: IpcWriteOptions,
+) -> Result<Vec<u8>> {
+    let mut output_file = Vec::new();
+
+    {
+        let mut writer = StreamWriter::try_new_with_options(&mut output_file, schema, options)?;
+
+        // Iterate over record batches, writing them to IPC stream
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+
+    // Note that this returns output_file directly instead of using
+    // writer.into_inner().to_vec() as the latter seems likely to incur an extra copy of the
+    // vec
+    Ok(output_file)
+}
+
+/// Write a schema and a sequence of record batches to an Arrow IPC File buffer.
+pub fn write_file<'a>(
+    schema: &Schema,
+    batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: IpcWriteOptions,
+) -> Result<Vec<u8>> {
+    let mut output_file = Vec::new();
+
+    {
+        let mut writer = FileWriter::try_new_with_options(&mut output_file, schema, options)?;
+
+        // Iterate over record batches, writing them to IPC file
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+
+    Ok(output_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_ipc::reader::{FileReader, StreamReader};
+    use arrow_schema::{DataType, Field};
+
+    use super::*;
+    use crate::ipc::WriteOptions;
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from((0..1000).collect::<Vec<_>>())),
+                Arc::new(StringArray::from(vec![Some("abc"); 1000])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn round_trip(options: &WriteOptions) -> (usize, usize) {
+        let batch = batch();
+
+        let stream = write_stream(&batch.schema(), [&batch], options.try_into().unwrap()).unwrap();
+        let reader = StreamReader::try_new(Cursor::new(&stream), None).unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch.clone()]);
+
+        let file = write_file(&batch.schema(), [&batch], options.try_into().unwrap()).unwrap();
+        let reader = FileReader::try_new(Cursor::new(&file), None).unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+
+        (stream.len(), file.len())
+    }
+
+    #[test]
+    fn uncompressed_round_trip() {
+        round_trip(&WriteOptions::default());
+    }
+
+    #[cfg(feature = "ipc_compression")]
+    #[test]
+    fn compressed_round_trip() {
+        use crate::ipc::IPCCompression;
+
+        let (stream_len, file_len) = round_trip(&WriteOptions::default());
+        for compression in [IPCCompression::Lz4Frame, IPCCompression::Zstd] {
+            let options = WriteOptions {
+                compression: Some(compression),
+                ..Default::default()
+            };
+            let (compressed_stream_len, compressed_file_len) = round_trip(&options);
+            assert!(compressed_stream_len < stream_len);
+            assert!(compressed_file_len < file_len);
+        }
+    }
+}
@@ -2,6 +2,8 @@ use wasm_bindgen::prelude::*;
 
 #[cfg(feature = "read_arrow_js")]
 pub mod arrow_js;
+pub mod column;
+pub mod compute;
 #[cfg(feature = "data")]
 pub mod data;
 #[cfg(feature = "data_type")]
@@ -10,12 +12,16 @@ pub mod error;
 pub mod ffi;
 #[cfg(feature = "field")]
 pub mod field;
+#[cfg(feature = "integration_json")]
+pub mod integration_json;
+pub mod ipc;
 #[cfg(feature = "record_batch")]
 pub mod record_batch;
 #[cfg(feature = "schema")]
 pub mod schema;
 #[cfg(feature = "table")]
 pub mod table;
+pub mod validation;
 #[cfg(feature = "vector")]
 pub mod vector;
 
@@ -29,6 +35,8 @@ pub use schema::Schema;
 #[cfg(feature = "table")]
 pub use table::Table;
 
+#[cfg(test)]
+mod test_util;
 mod utils;
 
 #[wasm_bindgen(typescript_custom_section)]
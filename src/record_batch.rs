@@ -1,11 +1,23 @@
+use crate::column::{
+    column_indices, remaining_column_indices, rename_columns, renames_from_js,
+    with_renamed_columns, ColumnRenames, ColumnSelector, ColumnSelectors,
+};
+use crate::compute::{filter_batch, mask_from_bitmap, take_batch};
 use crate::error::WasmResult;
-use crate::ffi::FFIData;
+use crate::ffi::layout::{ArrayLayout, BufferLayout};
+use crate::ffi::{FFIArrowArrayStream, FFIData, FFIDeviceData};
+use crate::ipc::{
+    encode_batch_message, read_stream_batch, write_stream, IPCMessageOptions, IPCReadOptions,
+    IPCWriteOptions, ReadOptions, WriteOptions,
+};
+use crate::validation::{Validation, ValidationLevel};
 use crate::ArrowWasmError;
+use arrow_array::{Array, StructArray, UInt32Array, UInt64Array};
 use wasm_bindgen::prelude::*;
 
 /// A group of columns of equal length in WebAssembly memory with an associated {@linkcode Schema}.
 #[wasm_bindgen]
-pub struct RecordBatch(arrow_array::RecordBatch);
+pub struct RecordBatch(pub(crate) arrow_array::RecordBatch);
 
 impl RecordBatch {
     pub fn new(batch: arrow_array::RecordBatch) -> Self {
@@ -19,6 +31,34 @@ impl RecordBatch {
 
 #[wasm_bindgen]
 impl RecordBatch {
+    /// Import a RecordBatch from an `ArrowArray` and `ArrowSchema` pair located in this
+    /// instance's WebAssembly memory, according to the Arrow C Data Interface.
+    ///
+    /// The array must be a struct array whose children are the columns of the batch. This takes
+    /// ownership of both structs: they are moved out of their original location and marked as
+    /// released, and the producer's release callback will be called once this RecordBatch is
+    /// freed.
+    ///
+    /// @param arrayAddr number pointer to an ArrowArray FFI struct in Wasm memory
+    /// @param schemaAddr number pointer to an ArrowSchema FFI struct in Wasm memory
+    /// @param validation how thoroughly to check the imported data, `"full"` by default
+    #[wasm_bindgen(js_name = fromFFI)]
+    pub fn from_ffi(
+        array_addr: usize,
+        schema_addr: usize,
+        validation: Option<Validation>,
+    ) -> WasmResult<RecordBatch> {
+        let validation = ValidationLevel::from_js(validation)?;
+        let batch = unsafe {
+            crate::ffi::import::import_record_batch(
+                array_addr as *mut _,
+                schema_addr as *mut _,
+                validation,
+            )
+        }?;
+        Ok(batch.into())
+    }
+
     /// The number of rows in this RecordBatch.
     #[wasm_bindgen(getter, js_name = numRows)]
     pub fn num_rows(&self) -> usize {
@@ -58,11 +98,107 @@ impl RecordBatch {
         Ok((&self.0).try_into()?)
     }
 
+    /// Export this RecordBatch to FFI structs according to the Arrow C Device Data Interface.
+    ///
+    /// This method **does not consume** the RecordBatch, so you must remember to call {@linkcode
+    /// RecordBatch.free} to release the resources. The underlying arrays are reference counted, so
+    /// this method does not copy data, it only prevents the data from being released.
+    #[wasm_bindgen(js_name = toFFIDevice)]
+    pub fn to_ffi_device(&self) -> WasmResult<FFIDeviceData> {
+        Ok(self.to_ffi()?.into())
+    }
+
+    /// Export this RecordBatch to FFI structs according to the Arrow C Device Data Interface.
+    ///
+    /// This method **does consume** the RecordBatch, so the original RecordBatch will be
+    /// inaccessible after this call. You must still call {@linkcode FFIDeviceData.free} after
+    /// you've finished using the FFIDeviceData.
+    #[wasm_bindgen(js_name = intoFFIDevice)]
+    pub fn into_ffi_device(self) -> WasmResult<FFIDeviceData> {
+        Ok(self.into_ffi()?.into())
+    }
+
+    /// Describe the physical layout of this RecordBatch in WebAssembly memory.
+    ///
+    /// The RecordBatch is described as a struct array whose children are its columns. See
+    /// {@linkcode Data.bufferLayout} for the structure of the returned object.
+    ///
+    /// The regions are only valid as long as this RecordBatch is alive, and views over them are
+    /// invalidated if WebAssembly memory grows.
+    #[wasm_bindgen(js_name = bufferLayout)]
+    pub fn buffer_layout(&self) -> WasmResult<BufferLayout> {
+        let data = StructArray::from(self.0.clone()).into_data();
+        ArrayLayout::from(&data).to_js()
+    }
+
+    /// Export this RecordBatch to a single-batch `ArrowArrayStream` according to the Arrow C
+    /// Stream Interface.
+    ///
+    /// This method **does not consume** the RecordBatch, so you must remember to call {@linkcode
+    /// RecordBatch.free} to release the resources. The underlying arrays are reference counted, so
+    /// this method does not copy data, it only prevents the data from being released.
+    #[wasm_bindgen(js_name = toArrowArrayStream)]
+    pub fn to_arrow_array_stream(&self) -> FFIArrowArrayStream {
+        FFIArrowArrayStream::from_batches(self.0.schema(), vec![self.0.clone()])
+    }
+
+    /// Export this RecordBatch to a single-batch `ArrowArrayStream` according to the Arrow C
+    /// Stream Interface.
+    ///
+    /// This method **does consume** the RecordBatch, so the original RecordBatch will be
+    /// inaccessible after this call. You must still call {@linkcode FFIArrowArrayStream.free}
+    /// after you've finished using the FFIArrowArrayStream.
+    #[wasm_bindgen(js_name = intoArrowArrayStream)]
+    pub fn into_arrow_array_stream(self) -> FFIArrowArrayStream {
+        FFIArrowArrayStream::from_batches(self.0.schema(), vec![self.0])
+    }
+
     /// Consume this RecordBatch and convert to an Arrow IPC Stream buffer
+    ///
+    /// @param options optional compression, alignment and metadata version of the output
     #[wasm_bindgen(js_name = intoIPCStream)]
-    pub fn into_ipc_stream(self) -> WasmResult<Vec<u8>> {
-        let table = crate::Table::new(self.0.schema(), vec![self.0]);
-        table.into_ipc_stream()
+    pub fn into_ipc_stream(self, options: Option<IPCWriteOptions>) -> WasmResult<Vec<u8>> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(write_stream(
+            self.0.schema_ref(),
+            [&self.0],
+            (&options).try_into()?,
+        )?)
+    }
+
+    /// Create a RecordBatch from an Arrow IPC Stream buffer
+    ///
+    /// The stream must hold a single RecordBatch unless `concatenate` is `true`, in which case
+    /// every batch in the stream is concatenated into one. A stream with no batches yields an
+    /// empty RecordBatch.
+    ///
+    /// @param concatenate whether to concatenate the batches of a stream holding more than one
+    /// @param options optional columns to decode, range of rows to read and validation level
+    #[wasm_bindgen(js_name = fromIPCStream)]
+    pub fn from_ipc_stream(
+        buf: &[u8],
+        concatenate: Option<bool>,
+        options: Option<IPCReadOptions>,
+    ) -> WasmResult<RecordBatch> {
+        let options = ReadOptions::from_js(options)?;
+        let batch = read_stream_batch(buf, concatenate.unwrap_or(false), &options)?;
+        Ok(Self::new(batch))
+    }
+
+    /// Encode this RecordBatch as encapsulated IPC messages, for sending over a custom transport:
+    /// a dictionary batch message for every dictionary-encoded column, followed by the record
+    /// batch message.
+    ///
+    /// Decode the messages with {@linkcode decodeIPCMessage}. To avoid sending the same
+    /// dictionaries with every batch, pass an {@linkcode IPCDictionaryTracker} as the
+    /// `dictionaryTracker` option, and an {@linkcode IPCDictionaryMemo} as the `dictionaryMemo`
+    /// option of `decodeIPCMessage`.
+    ///
+    /// @param options optional compression, alignment and metadata version of the output, and the
+    /// tracker of dictionaries already sent for this schema
+    #[wasm_bindgen(js_name = toIPCMessage)]
+    pub fn to_ipc_message(&self, options: Option<IPCMessageOptions>) -> WasmResult<Vec<u8>> {
+        encode_batch_message(&self.0, options)
     }
 
     /// Override the schema of this [`RecordBatch`]
@@ -82,6 +218,92 @@ impl RecordBatch {
         self.0.slice(offset, length).into()
     }
 
+    /// Return a new RecordBatch with only the rows where `mask` is `true`.
+    ///
+    /// Rows where `mask` is null are removed.
+    ///
+    /// @param mask a boolean Data with one value per row
+    #[cfg(feature = "data")]
+    #[wasm_bindgen]
+    pub fn filter(&self, mask: &crate::data::Data) -> WasmResult<RecordBatch> {
+        let mask = crate::compute::mask_from_chunks(std::slice::from_ref(mask.as_ref()))?;
+        Ok(filter_batch(&self.0, &mask)?.into())
+    }
+
+    /// Return a new RecordBatch with only the rows whose bit is set in `mask`.
+    ///
+    /// @param mask a bitmap with one bit per row, least significant bit first, as in the validity
+    /// bitmaps of the Arrow format
+    #[wasm_bindgen(js_name = filterBitmap)]
+    pub fn filter_bitmap(&self, mask: &[u8]) -> WasmResult<RecordBatch> {
+        let mask = mask_from_bitmap(mask, self.0.num_rows())?;
+        Ok(filter_batch(&self.0, &mask)?.into())
+    }
+
+    /// Return a new RecordBatch with the rows at `indices`, in that order.
+    ///
+    /// Rows may be repeated. Returns an error if an index is out of bounds.
+    ///
+    /// @param indices the positional indices of the rows to take
+    #[wasm_bindgen]
+    pub fn take(&self, indices: Vec<u32>) -> WasmResult<RecordBatch> {
+        let indices = UInt32Array::from(indices);
+        Ok(take_batch(&self.0, &indices)?.into())
+    }
+
+    /// Return a new RecordBatch with the rows at `indices`, in that order.
+    ///
+    /// @param indices the positional indices of the rows to take
+    #[wasm_bindgen(js_name = takeBigUint64)]
+    pub fn take_big_uint64(&self, indices: Vec<u64>) -> WasmResult<RecordBatch> {
+        let indices = UInt64Array::from(indices);
+        Ok(take_batch(&self.0, &indices)?.into())
+    }
+
+    /// Return a new RecordBatch with the rows at `indices`, in that order.
+    ///
+    /// Null indices produce rows of nulls.
+    ///
+    /// @param indices an integer Data of the positional indices of the rows to take
+    #[cfg(feature = "data")]
+    #[wasm_bindgen(js_name = takeData)]
+    pub fn take_data(&self, indices: &crate::data::Data) -> WasmResult<RecordBatch> {
+        Ok(take_batch(&self.0, indices.as_ref())?.into())
+    }
+
+    /// Return a new RecordBatch with only the given columns, in the given order.
+    ///
+    /// The columns share memory with this RecordBatch, and the schema metadata is kept.
+    ///
+    /// @param columns the names or positional indices of the columns to keep. A column may not be
+    /// selected more than once.
+    #[wasm_bindgen]
+    pub fn select(&self, columns: ColumnSelectors) -> WasmResult<RecordBatch> {
+        let columns = ColumnSelector::from_js_array(columns)?;
+        let indices = column_indices(self.0.schema_ref(), &columns)?;
+        Ok(self.0.project(&indices)?.into())
+    }
+
+    /// Return a new RecordBatch without the given columns.
+    ///
+    /// @param columns the names or positional indices of the columns to remove
+    #[wasm_bindgen(js_name = drop)]
+    pub fn drop_columns(&self, columns: ColumnSelectors) -> WasmResult<RecordBatch> {
+        let columns = ColumnSelector::from_js_array(columns)?;
+        let indices = remaining_column_indices(self.0.schema_ref(), &columns)?;
+        Ok(self.0.project(&indices)?.into())
+    }
+
+    /// Return a new RecordBatch with some of its columns renamed.
+    ///
+    /// @param mapping an object mapping current column names to new names
+    #[wasm_bindgen(js_name = renameColumns)]
+    pub fn rename_columns(&self, mapping: ColumnRenames) -> WasmResult<RecordBatch> {
+        let renames = renames_from_js(mapping)?;
+        let schema = rename_columns(self.0.schema_ref(), &renames)?;
+        Ok(with_renamed_columns(&self.0, schema)?.into())
+    }
+
     /// Returns the total number of bytes of memory occupied physically by this batch.
     #[wasm_bindgen(js_name = getArrayMemorySize)]
     pub fn get_array_memory_size(&self) -> usize {
@@ -1,10 +1,10 @@
-use arrow_ipc::writer::StreamWriter;
 use std::collections::HashMap;
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
 use crate::error::WasmResult;
 use crate::ffi::FFISchema;
+use crate::ipc::{encode_schema, read_stream_schema, write_stream, IPCWriteOptions, WriteOptions};
 
 #[wasm_bindgen(typescript_custom_section)]
 const TS_SchemaMetadata: &'static str = r#"
@@ -28,6 +28,20 @@ pub struct Schema(pub(crate) arrow_schema::SchemaRef);
 
 #[wasm_bindgen]
 impl Schema {
+    /// Read a Schema from an `ArrowSchema` struct located in this instance's WebAssembly memory,
+    /// according to the Arrow C Data Interface.
+    ///
+    /// The struct must describe a struct type, whose children are the fields of the schema,
+    /// including their dictionaries, flags and metadata. This **does not take ownership** of the
+    /// struct: its contents are copied, and the caller remains responsible for releasing it.
+    ///
+    /// @param addr number pointer to an ArrowSchema FFI struct in Wasm memory
+    #[wasm_bindgen(js_name = fromFFI)]
+    pub fn from_ffi(addr: usize) -> WasmResult<Schema> {
+        let schema = unsafe { crate::ffi::import::read_schema(addr as *const _) }?;
+        Ok(schema.into())
+    }
+
     /// Export this schema to an FFISchema object, which can be read with arrow-js-ffi.
     ///
     /// This method **does not consume** the Schema, so you must remember to call {@linkcode
@@ -49,20 +63,33 @@ impl Schema {
     }
 
     /// Consume this schema and convert to an Arrow IPC Stream buffer
+    ///
+    /// @param options optional alignment and metadata version of the output
     #[wasm_bindgen(js_name = intoIPCStream)]
-    pub fn into_ipc_stream(self) -> WasmResult<Vec<u8>> {
-        let mut output_file = Vec::new();
-
-        {
-            let mut writer = StreamWriter::try_new(&mut output_file, &self.0)?;
+    pub fn into_ipc_stream(self, options: Option<IPCWriteOptions>) -> WasmResult<Vec<u8>> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(write_stream(&self.0, [], (&options).try_into()?)?)
+    }
 
-            writer.finish()?;
-        }
+    /// Read a schema from an Arrow IPC Stream buffer
+    ///
+    /// Only the schema message at the start of the stream is decoded; any record batches that
+    /// follow it are ignored.
+    #[wasm_bindgen(js_name = fromIPCStream)]
+    pub fn from_ipc_stream(buf: &[u8]) -> WasmResult<Schema> {
+        Ok(read_stream_schema(buf)?.into())
+    }
 
-        // Note that this returns output_file directly instead of using
-        // writer.into_inner().to_vec() as the latter seems likely to incur an extra copy of the
-        // vec
-        Ok(output_file)
+    /// Encode this schema as a single encapsulated IPC message, for sending over a custom
+    /// transport.
+    ///
+    /// Decode the message with {@linkcode decodeIPCMessage}.
+    ///
+    /// @param options optional alignment and metadata version of the output
+    #[wasm_bindgen(js_name = toIPCMessage)]
+    pub fn to_ipc_message(&self, options: Option<IPCWriteOptions>) -> WasmResult<Vec<u8>> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(encode_schema(&self.0, &(&options).try_into()?)?)
     }
 
     /// Returns an immutable reference of a specific [`Field`] instance selected using an
@@ -1,13 +1,61 @@
+use crate::column::{
+    column_indices, remaining_column_indices, rename_columns, renames_from_js,
+    with_renamed_columns, ColumnRenames, ColumnSelector, ColumnSelectors,
+};
+use crate::compute::{
+    filter_batches, mask_from_bitmap, sort_to_indices, take_batches, SortKey, SortKeys,
+};
 use crate::error::WasmResult;
-use crate::ffi::{FFISchema, FFIStream};
+use crate::ffi::{FFIArrowArrayStream, FFIArrowDeviceArrayStream, FFIRecordBatchReader, FFIStream};
+use crate::ipc::{
+    is_ipc_file, read_buffer, read_file, read_stream, write_file, write_stream, IPCReadOptions,
+    IPCWriteOptions, ReadOptions, WasmBuffer, WriteOptions,
+};
+use crate::validation::{Validation, ValidationLevel};
 use crate::ArrowWasmError;
-use arrow_array::ffi;
-use arrow_array::{Array, StructArray};
-use arrow_ipc::reader::StreamReader;
-use arrow_ipc::writer::StreamWriter;
-use std::io::Cursor;
+use arrow_array::{Array, ArrayRef, StructArray, UInt32Array, UInt64Array};
+use arrow_schema::ArrowError;
+use arrow_select::concat::concat_batches;
+use serde::Deserialize;
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen(typescript_custom_section)]
+const TS_RechunkOptions: &'static str = r#"
+export type RechunkOptions = {
+  maxRows?: number | null;
+  maxBytes?: number | null;
+};
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "RechunkOptions")]
+    pub type RechunkOptions;
+}
+
+/// Target sizes for the batches of a rechunked [Table].
+///
+/// Each batch holds at most `max_rows` rows and at most `max_bytes` bytes, estimated from the
+/// average size of a row of each input batch. A batch always holds at least one row, and without
+/// either limit every row ends up in a single batch.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkLimits {
+    pub max_rows: Option<usize>,
+    pub max_bytes: Option<usize>,
+}
+
+impl ChunkLimits {
+    /// Parse chunk limits from a JS object, with no limits if `options` is not provided.
+    pub fn from_js(options: Option<RechunkOptions>) -> WasmResult<Self> {
+        match options {
+            Some(options) => Ok(serde_wasm_bindgen::from_value(options.into())?),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
 /// A Table in WebAssembly memory conforming to the Apache Arrow spec.
 ///
 /// A Table consists of one or more {@linkcode RecordBatch} objects plus a {@linkcode Schema} that
@@ -46,6 +94,86 @@ impl Table {
     pub fn into_inner(self) -> (arrow_schema::SchemaRef, Vec<arrow_array::RecordBatch>) {
         (self.schema, self.batches)
     }
+
+    /// Returns a new table whose batches are regrouped to fit within `limits`.
+    ///
+    /// Rows keep their order. Input batches are sliced where a limit is reached, and only the
+    /// output batches made up of several slices are copied. A table without any rows yields a
+    /// single empty batch, like [Table::combine_batches].
+    pub fn rechunk_batches(&self, limits: &ChunkLimits) -> crate::error::Result<Self> {
+        let max_rows = limits.max_rows.unwrap_or(usize::MAX);
+        let max_bytes = limits.max_bytes.unwrap_or(usize::MAX);
+        if max_rows == 0 {
+            return Err(
+                ArrowError::InvalidArgumentError("maxRows must be positive".to_string()).into(),
+            );
+        }
+
+        let mut batches = vec![];
+        let mut pending = vec![];
+        let (mut pending_rows, mut pending_bytes) = (0, 0);
+        for batch in &self.batches {
+            let row_bytes = average_row_size(batch)?;
+            let mut offset = 0;
+            while offset < batch.num_rows() {
+                let fits = (max_rows - pending_rows)
+                    .min(max_bytes.saturating_sub(pending_bytes) / row_bytes.max(1))
+                    .min(batch.num_rows() - offset);
+                // Always make progress, even when a single row exceeds `max_bytes`
+                let length = if pending.is_empty() {
+                    fits.max(1)
+                } else {
+                    fits
+                };
+                if length > 0 {
+                    pending.push(batch.slice(offset, length));
+                    pending_rows += length;
+                    pending_bytes += length * row_bytes;
+                    offset += length;
+                }
+                if offset < batch.num_rows() || pending_rows == max_rows {
+                    batches.push(self.concat(&pending)?);
+                    pending.clear();
+                    (pending_rows, pending_bytes) = (0, 0);
+                }
+            }
+        }
+        if !pending.is_empty() {
+            batches.push(self.concat(&pending)?);
+        }
+        // Keep a batch for the schema to travel with, e.g. through IPC
+        if batches.is_empty() {
+            batches.push(arrow_array::RecordBatch::new_empty(self.schema.clone()));
+        }
+        Ok(Self::new(self.schema.clone(), batches))
+    }
+
+    fn take_rows(&self, indices: &dyn Array) -> WasmResult<Table> {
+        let batch = take_batches(&self.schema, &self.batches, indices)?;
+        Ok(Self::new(self.schema.clone(), vec![batch]))
+    }
+
+    /// Concatenate `batches` into a single batch, without copying if there is only one.
+    fn concat(
+        &self,
+        batches: &[arrow_array::RecordBatch],
+    ) -> crate::error::Result<arrow_array::RecordBatch> {
+        match batches {
+            [batch] => Ok(batch.clone()),
+            batches => Ok(concat_batches(&self.schema, batches)?),
+        }
+    }
+
+    /// Returns a new table with only the columns at `indices`, in that order.
+    pub fn project(&self, indices: &[usize]) -> Result<Self, arrow_schema::ArrowError> {
+        let schema = Arc::new(self.schema.project(indices)?);
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| batch.project(indices))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(schema, batches))
+    }
 }
 
 #[wasm_bindgen]
@@ -77,12 +205,242 @@ impl Table {
             .collect()
     }
 
+    /// Access a column of the Table by index as a chunked {@linkcode Vector}.
+    ///
+    /// The returned Vector has one chunk per RecordBatch of the Table and shares its underlying
+    /// memory, so this does not copy data.
+    ///
+    /// @param index The positional index of the column to retrieve.
+    #[cfg(feature = "vector")]
+    #[wasm_bindgen]
+    pub fn column(&self, index: usize) -> WasmResult<crate::vector::Vector> {
+        if index >= self.schema.fields().len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Column index {index} out of range for table with {} columns",
+                self.schema.fields().len()
+            ))
+            .into());
+        }
+        let chunks = self
+            .batches
+            .iter()
+            .map(|batch| batch.column(index).clone())
+            .collect();
+        Ok(crate::vector::Vector::try_new(
+            chunks,
+            self.schema.fields()[index].clone(),
+        )?)
+    }
+
     /// The number of batches in the Table
     #[wasm_bindgen(getter, js_name = numBatches)]
     pub fn num_batches(&self) -> usize {
         self.batches.len()
     }
 
+    /// The total number of rows across all batches of the Table
+    #[wasm_bindgen(getter, js_name = numRows)]
+    pub fn num_rows(&self) -> usize {
+        self.batches.iter().map(|batch| batch.num_rows()).sum()
+    }
+
+    /// Return a new Table with `length` rows starting at row `offset`.
+    ///
+    /// Only the batches that overlap the range are kept, and the first and last of them are
+    /// sliced, so this does not copy data. The range is clamped to the rows of the Table.
+    ///
+    /// @param offset the index of the first row to keep
+    /// @param length the number of rows to keep
+    #[wasm_bindgen]
+    pub fn slice(&self, offset: usize, length: usize) -> Table {
+        let mut skip = offset;
+        let mut remaining = length;
+        let mut batches = vec![];
+        for batch in &self.batches {
+            if remaining == 0 {
+                break;
+            }
+            if skip >= batch.num_rows() {
+                skip -= batch.num_rows();
+                continue;
+            }
+            let length = (batch.num_rows() - skip).min(remaining);
+            batches.push(batch.slice(skip, length));
+            remaining -= length;
+            skip = 0;
+        }
+        Self::new(self.schema.clone(), batches)
+    }
+
+    /// Return a new Table with the first `n` rows, or every row if there are fewer than `n`.
+    #[wasm_bindgen]
+    pub fn head(&self, n: usize) -> Table {
+        self.slice(0, n)
+    }
+
+    /// Return a new Table with the last `n` rows, or every row if there are fewer than `n`.
+    #[wasm_bindgen]
+    pub fn tail(&self, n: usize) -> Table {
+        let num_rows = self.num_rows();
+        self.slice(num_rows.saturating_sub(n), n)
+    }
+
+    /// Return a new Table with only the rows where `mask` is `true`.
+    ///
+    /// The chunks of `mask` do not need to line up with the batches of the Table. Rows where
+    /// `mask` is null are removed, as are batches left without any rows.
+    ///
+    /// @param mask a boolean Vector with one value per row
+    #[cfg(feature = "vector")]
+    #[wasm_bindgen]
+    pub fn filter(&self, mask: &crate::vector::Vector) -> WasmResult<Table> {
+        let mask = crate::compute::mask_from_chunks(mask.as_ref())?;
+        let batches = filter_batches(&self.batches, &mask)?;
+        Ok(Self::new(self.schema.clone(), batches))
+    }
+
+    /// Return a new Table with only the rows where `masks` is `true`.
+    ///
+    /// This is the same as {@linkcode Table.filter}, with the mask given as an array of Data
+    /// chunks. The Data objects are consumed.
+    ///
+    /// @param masks boolean Data chunks with one value per row of the Table in total
+    #[cfg(feature = "data")]
+    #[wasm_bindgen(js_name = filterChunks)]
+    pub fn filter_chunks(&self, masks: Vec<crate::data::Data>) -> WasmResult<Table> {
+        let chunks = masks
+            .iter()
+            .map(|mask| mask.as_ref().clone())
+            .collect::<Vec<_>>();
+        let mask = crate::compute::mask_from_chunks(&chunks)?;
+        let batches = filter_batches(&self.batches, &mask)?;
+        Ok(Self::new(self.schema.clone(), batches))
+    }
+
+    /// Return a new Table with only the rows whose bit is set in `mask`.
+    ///
+    /// @param mask a bitmap with one bit per row of the Table, least significant bit first, as in
+    /// the validity bitmaps of the Arrow format
+    #[wasm_bindgen(js_name = filterBitmap)]
+    pub fn filter_bitmap(&self, mask: &[u8]) -> WasmResult<Table> {
+        let mask = mask_from_bitmap(mask, self.num_rows())?;
+        let batches = filter_batches(&self.batches, &mask)?;
+        Ok(Self::new(self.schema.clone(), batches))
+    }
+
+    /// Return a new Table with the rows at `indices`, in that order, in a single RecordBatch.
+    ///
+    /// Indices refer to rows of the whole Table, across batch boundaries. Rows may be repeated.
+    /// Returns an error if an index is out of bounds.
+    ///
+    /// @param indices the positional indices of the rows to take
+    #[wasm_bindgen]
+    pub fn take(&self, indices: Vec<u32>) -> WasmResult<Table> {
+        self.take_rows(&UInt32Array::from(indices))
+    }
+
+    /// Return a new Table with the rows at `indices`, in that order, in a single RecordBatch.
+    ///
+    /// @param indices the positional indices of the rows to take
+    #[wasm_bindgen(js_name = takeBigUint64)]
+    pub fn take_big_uint64(&self, indices: Vec<u64>) -> WasmResult<Table> {
+        self.take_rows(&UInt64Array::from(indices))
+    }
+
+    /// Return a new Table with the rows at `indices`, in that order, in a single RecordBatch.
+    ///
+    /// Null indices produce rows of nulls.
+    ///
+    /// @param indices an integer Data of the positional indices of the rows to take
+    #[cfg(feature = "data")]
+    #[wasm_bindgen(js_name = takeData)]
+    pub fn take_data(&self, indices: &crate::data::Data) -> WasmResult<Table> {
+        self.take_rows(indices.as_ref().as_ref())
+    }
+
+    /// Return a new Table sorted by one or more columns, in a single RecordBatch.
+    ///
+    /// Rows are ordered by the first key, then by the second key where the first is equal, and so
+    /// on. The sort is stable.
+    ///
+    /// @param keys the columns to sort by, each with an optional direction and null ordering
+    #[wasm_bindgen(js_name = sortBy)]
+    pub fn sort_by(&self, keys: SortKeys) -> WasmResult<Table> {
+        let indices = self.argsort(keys)?;
+        self.take_rows(&UInt32Array::from(indices))
+    }
+
+    /// Returns the indices of the rows of the Table in sorted order, without reordering them.
+    ///
+    /// The indices can be passed to {@linkcode Table.take} to apply the ordering.
+    ///
+    /// @param keys the columns to sort by, each with an optional direction and null ordering
+    #[wasm_bindgen]
+    pub fn argsort(&self, keys: SortKeys) -> WasmResult<Vec<u32>> {
+        let keys = SortKey::from_js_array(keys)?;
+        Ok(sort_to_indices(&self.schema, &self.batches, &keys)?)
+    }
+
+    /// Return a new Table with every RecordBatch concatenated into a single RecordBatch.
+    ///
+    /// This copies the data of every column. A Table without any rows yields a single empty
+    /// RecordBatch.
+    #[wasm_bindgen(js_name = combineBatches)]
+    pub fn combine_batches(&self) -> WasmResult<Table> {
+        let batch = concat_batches(&self.schema, &self.batches)?;
+        Ok(Self::new(self.schema.clone(), vec![batch]))
+    }
+
+    /// Return a new Table with its rows regrouped into batches of a target size.
+    ///
+    /// Small batches are concatenated and large batches are sliced so that each batch holds at
+    /// most `maxRows` rows and approximately at most `maxBytes` bytes. A batch always holds at
+    /// least one row, except that a Table without any rows yields a single empty RecordBatch, as
+    /// with {@linkcode Table.combineBatches}.
+    ///
+    /// @param options optional limits on the number of rows and bytes of each batch
+    #[wasm_bindgen]
+    pub fn rechunk(&self, options: Option<RechunkOptions>) -> WasmResult<Table> {
+        let limits = ChunkLimits::from_js(options)?;
+        Ok(self.rechunk_batches(&limits)?)
+    }
+
+    /// Return a new Table with only the given columns, in the given order.
+    ///
+    /// The columns share memory with this Table, and the schema metadata is kept.
+    ///
+    /// @param columns the names or positional indices of the columns to keep. A column may not be
+    /// selected more than once.
+    #[wasm_bindgen]
+    pub fn select(&self, columns: ColumnSelectors) -> WasmResult<Table> {
+        let columns = ColumnSelector::from_js_array(columns)?;
+        Ok(self.project(&column_indices(&self.schema, &columns)?)?)
+    }
+
+    /// Return a new Table without the given columns.
+    ///
+    /// @param columns the names or positional indices of the columns to remove
+    #[wasm_bindgen(js_name = drop)]
+    pub fn drop_columns(&self, columns: ColumnSelectors) -> WasmResult<Table> {
+        let columns = ColumnSelector::from_js_array(columns)?;
+        Ok(self.project(&remaining_column_indices(&self.schema, &columns)?)?)
+    }
+
+    /// Return a new Table with some of its columns renamed.
+    ///
+    /// @param mapping an object mapping current column names to new names
+    #[wasm_bindgen(js_name = renameColumns)]
+    pub fn rename_columns(&self, mapping: ColumnRenames) -> WasmResult<Table> {
+        let renames = renames_from_js(mapping)?;
+        let schema = rename_columns(&self.schema, &renames)?;
+        let batches = self
+            .batches
+            .iter()
+            .map(|batch| with_renamed_columns(batch, schema.clone()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(schema, batches))
+    }
+
     /// Export this Table to FFI structs according to the Arrow C Data Interface.
     ///
     /// This method **does not consume** the Table, so you must remember to call {@linkcode
@@ -103,40 +461,202 @@ impl Table {
         Ok((&self).try_into()?)
     }
 
-    /// Consume this table and convert to an Arrow IPC Stream buffer
-    #[wasm_bindgen(js_name = intoIPCStream)]
-    pub fn into_ipc_stream(self) -> WasmResult<Vec<u8>> {
-        let mut output_file = Vec::new();
+    /// Export this Table to a lazily-converted {@linkcode FFIRecordBatchReader}.
+    ///
+    /// This method **does not consume** the Table, so you must remember to call {@linkcode
+    /// Table.free} to release the resources. Only the FFI structs of the batch currently being
+    /// read are held by the reader, but the batches themselves stay alive as long as the Table.
+    #[wasm_bindgen(js_name = toFFIReader)]
+    pub fn to_ffi_reader(&self) -> WasmResult<FFIRecordBatchReader> {
+        Ok(FFIRecordBatchReader::from_batches(
+            self.schema.clone(),
+            self.batches.clone(),
+        )?)
+    }
+
+    /// Export this Table to a lazily-converted {@linkcode FFIRecordBatchReader}.
+    ///
+    /// This method **does consume** the Table, so the original Table will be
+    /// inaccessible after this call. Each batch is dropped once the reader has moved past it,
+    /// which keeps peak memory low when handing a large table to JS one batch at a time. You
+    /// must still call {@linkcode FFIRecordBatchReader.free} after you've finished using the
+    /// FFIRecordBatchReader.
+    #[wasm_bindgen(js_name = intoFFIReader)]
+    pub fn into_ffi_reader(self) -> WasmResult<FFIRecordBatchReader> {
+        Ok(FFIRecordBatchReader::from_batches(
+            self.schema,
+            self.batches,
+        )?)
+    }
+
+    /// Export this Table to an `ArrowArrayStream` according to the Arrow C Stream Interface.
+    ///
+    /// This method **does not consume** the Table, so you must remember to call {@linkcode
+    /// Table.free} to release the resources. The underlying arrays are reference counted, so
+    /// this method does not copy data, it only prevents the data from being released.
+    #[wasm_bindgen(js_name = toArrowArrayStream)]
+    pub fn to_arrow_array_stream(&self) -> FFIArrowArrayStream {
+        FFIArrowArrayStream::from_batches(self.schema.clone(), self.batches.clone())
+    }
 
-        {
-            let mut writer = StreamWriter::try_new(&mut output_file, &self.schema)?;
+    /// Export this Table to an `ArrowArrayStream` according to the Arrow C Stream Interface.
+    ///
+    /// This method **does consume** the Table, so the original Table will be
+    /// inaccessible after this call. You must still call {@linkcode FFIArrowArrayStream.free}
+    /// after you've finished using the FFIArrowArrayStream.
+    #[wasm_bindgen(js_name = intoArrowArrayStream)]
+    pub fn into_arrow_array_stream(self) -> FFIArrowArrayStream {
+        FFIArrowArrayStream::from_batches(self.schema, self.batches)
+    }
 
-            // Iterate over record batches, writing them to IPC stream
-            for chunk in self.batches {
-                writer.write(&chunk)?;
-            }
-            writer.finish()?;
-        }
+    /// Export this Table to an `ArrowDeviceArrayStream` according to the Arrow C Device Data
+    /// Interface.
+    ///
+    /// This method **does not consume** the Table, so you must remember to call {@linkcode
+    /// Table.free} to release the resources. The underlying arrays are reference counted, so
+    /// this method does not copy data, it only prevents the data from being released.
+    #[wasm_bindgen(js_name = toArrowDeviceArrayStream)]
+    pub fn to_arrow_device_array_stream(&self) -> FFIArrowDeviceArrayStream {
+        self.to_arrow_array_stream().into()
+    }
+
+    /// Export this Table to an `ArrowDeviceArrayStream` according to the Arrow C Device Data
+    /// Interface.
+    ///
+    /// This method **does consume** the Table, so the original Table will be
+    /// inaccessible after this call. You must still call {@linkcode
+    /// FFIArrowDeviceArrayStream.free} after you've finished using the
+    /// FFIArrowDeviceArrayStream.
+    #[wasm_bindgen(js_name = intoArrowDeviceArrayStream)]
+    pub fn into_arrow_device_array_stream(self) -> FFIArrowDeviceArrayStream {
+        self.into_arrow_array_stream().into()
+    }
+
+    /// Import a Table from an `ArrowArrayStream` located in this instance's WebAssembly memory,
+    /// according to the Arrow C Stream Interface.
+    ///
+    /// This takes ownership of the stream: it is moved out of its original location, every batch
+    /// is pulled from it, and it is then released.
+    ///
+    /// @param streamAddr number pointer to an ArrowArrayStream FFI struct in Wasm memory
+    /// @param validation how thoroughly to check each imported batch, `"full"` by default
+    #[wasm_bindgen(js_name = fromFFIStream)]
+    pub fn from_ffi_stream(
+        stream_addr: usize,
+        validation: Option<Validation>,
+    ) -> WasmResult<Table> {
+        let validation = ValidationLevel::from_js(validation)?;
+        let (schema, batches) =
+            unsafe { crate::ffi::import::import_stream(stream_addr as *mut _, validation) }?;
+        Ok(Self::new(schema, batches))
+    }
 
-        // Note that this returns output_file directly instead of using
-        // writer.into_inner().to_vec() as the latter seems likely to incur an extra copy of the
-        // vec
-        Ok(output_file)
+    /// Consume this table and convert to an Arrow IPC Stream buffer
+    ///
+    /// @param options optional compression, alignment and metadata version of the output
+    #[wasm_bindgen(js_name = intoIPCStream)]
+    pub fn into_ipc_stream(self, options: Option<IPCWriteOptions>) -> WasmResult<Vec<u8>> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(write_stream(
+            &self.schema,
+            &self.batches,
+            (&options).try_into()?,
+        )?)
     }
 
     /// Create a table from an Arrow IPC Stream buffer
+    ///
+    /// LZ4 and ZSTD compressed buffers are decompressed when the `ipc_compression` feature is
+    /// enabled.
+    ///
+    /// @param options optional columns to decode, range of rows to read and validation level
     #[wasm_bindgen(js_name = fromIPCStream)]
-    pub fn from_ipc_stream(buf: &[u8]) -> WasmResult<Table> {
-        let input_file = Cursor::new(buf);
-        let arrow_ipc_reader = StreamReader::try_new(input_file, None)?;
-        let schema = arrow_ipc_reader.schema();
+    pub fn from_ipc_stream(buf: &[u8], options: Option<IPCReadOptions>) -> WasmResult<Table> {
+        let options = ReadOptions::from_js(options)?;
+        let (schema, batches) = read_stream(buf, &options)?;
+        Ok(Self::new(schema, batches))
+    }
 
-        let mut batches = vec![];
-        for maybe_chunk in arrow_ipc_reader {
-            let chunk = maybe_chunk?;
-            batches.push(chunk);
+    /// Consume this table and convert to an Arrow IPC File buffer
+    ///
+    /// Unlike the IPC Stream format, the IPC File format ends with a footer that allows random
+    /// access to each RecordBatch. Files in this format often use the `.arrow` extension.
+    ///
+    /// @param options optional compression, alignment and metadata version of the output
+    #[wasm_bindgen(js_name = intoIPCFile)]
+    pub fn into_ipc_file(self, options: Option<IPCWriteOptions>) -> WasmResult<Vec<u8>> {
+        let options = WriteOptions::from_js(options)?;
+        Ok(write_file(
+            &self.schema,
+            &self.batches,
+            (&options).try_into()?,
+        )?)
+    }
+
+    /// Create a table from an Arrow IPC File buffer
+    ///
+    /// LZ4 and ZSTD compressed buffers are decompressed when the `ipc_compression` feature is
+    /// enabled.
+    ///
+    /// @param options optional columns to decode, range of rows to read and validation level
+    #[wasm_bindgen(js_name = fromIPCFile)]
+    pub fn from_ipc_file(buf: &[u8], options: Option<IPCReadOptions>) -> WasmResult<Table> {
+        let options = ReadOptions::from_js(options)?;
+        let (schema, batches) = read_file(buf, &options)?;
+        Ok(Self::new(schema, batches))
+    }
+
+    /// Create a table from an Arrow IPC buffer in either the Stream or the File format.
+    ///
+    /// The format is detected from the magic bytes `ARROW1` that start every IPC File.
+    ///
+    /// @param options optional columns to decode, range of rows to read and validation level
+    #[wasm_bindgen(js_name = fromIPC)]
+    pub fn from_ipc(buf: &[u8], options: Option<IPCReadOptions>) -> WasmResult<Table> {
+        if is_ipc_file(buf) {
+            Self::from_ipc_file(buf, options)
+        } else {
+            Self::from_ipc_stream(buf, options)
         }
+    }
+
+    /// Create a table from an Arrow IPC buffer in WebAssembly memory without copying it.
+    ///
+    /// The buffer may hold either the Stream or the File format, and is consumed: the arrays of
+    /// the table reference slices of it directly. Compressed bodies are still decompressed into
+    /// new allocations.
+    ///
+    /// @param buffer a buffer created with {@linkcode allocBuffer} and filled with an IPC payload
+    /// @param options optional columns to decode, range of rows to read and validation level
+    #[wasm_bindgen(js_name = fromIPCBuffer)]
+    pub fn from_ipc_buffer(
+        buffer: WasmBuffer,
+        options: Option<IPCReadOptions>,
+    ) -> WasmResult<Table> {
+        let options = ReadOptions::from_js(options)?;
+        let (schema, batches) = read_buffer(buffer.into_buffer(), &options)?;
+        Ok(Self::new(schema, batches))
+    }
+
+    /// Convert this table to the JSON format used by the Arrow integration tests
+    ///
+    /// The output is a verbose, human-readable rendering of the schema and every RecordBatch that
+    /// can be checked into version control and compared against other Arrow implementations.
+    /// Every RecordBatch must use the same dictionary for each dictionary-encoded column.
+    #[cfg(feature = "integration_json")]
+    #[wasm_bindgen(js_name = toIntegrationJSON)]
+    pub fn to_integration_json(&self) -> WasmResult<String> {
+        Ok(crate::integration_json::write_json(
+            &self.schema,
+            &self.batches,
+        )?)
+    }
 
+    /// Create a table from the JSON format used by the Arrow integration tests
+    #[cfg(feature = "integration_json")]
+    #[wasm_bindgen(js_name = fromIntegrationJSON)]
+    pub fn from_integration_json(json: &str) -> WasmResult<Table> {
+        let (schema, batches) = crate::integration_json::read_json(json)?;
         Ok(Self::new(schema, batches))
     }
 
@@ -150,22 +670,149 @@ impl Table {
     }
 }
 
+/// The average number of bytes of memory referenced by a row of `batch`.
+fn average_row_size(batch: &arrow_array::RecordBatch) -> crate::error::Result<usize> {
+    if batch.num_rows() == 0 {
+        return Ok(0);
+    }
+    let mut bytes = 0;
+    for column in batch.columns() {
+        bytes += column.to_data().get_slice_memory_size()?;
+    }
+    Ok((bytes + batch.num_rows() - 1) / batch.num_rows())
+}
+
 impl TryFrom<&Table> for FFIStream {
     type Error = ArrowWasmError;
 
     fn try_from(value: &Table) -> Result<Self, Self::Error> {
-        let schema = FFISchema::from_arrow(value.schema.as_ref())?;
+        let arrays = value
+            .batches
+            .iter()
+            .map(|batch| Arc::new(StructArray::from(batch.clone())) as ArrayRef)
+            .collect::<Vec<_>>();
+        FFIStream::from_arrow(value.schema.as_ref(), &arrays)
+    }
+}
 
-        let mut ffi_batches = Vec::with_capacity(value.num_batches());
-        for batch in value.batches.iter() {
-            ffi_batches.push(ffi::FFI_ArrowArray::new(
-                &StructArray::from(batch.clone()).into_data(),
-            ));
-        }
+#[cfg(test)]
+mod tests {
+    use arrow_array::RecordBatch;
+
+    use super::*;
+    use crate::test_util::three_batch_table;
+
+    fn table() -> Table {
+        let (schema, batches) = three_batch_table();
+        Table::new(schema, batches)
+    }
+
+    #[test]
+    fn ipc_format_detection() {
+        let (schema, batches) = table().into_inner();
+
+        let file = table().into_ipc_file(None).unwrap();
+        assert!(is_ipc_file(&file));
+        let from_file = Table::from_ipc(&file, None).unwrap();
+        assert_eq!(from_file.schema, schema);
+        assert_eq!(from_file.batches, batches);
+
+        let stream = table().into_ipc_stream(None).unwrap();
+        assert!(!is_ipc_file(&stream));
+        let from_stream = Table::from_ipc(&stream, None).unwrap();
+        assert_eq!(from_stream.batches, batches);
+    }
+
+    #[test]
+    fn project_columns() {
+        let table = table();
+        let projected = table.project(&[]).unwrap();
+        assert_eq!(projected.schema.fields().len(), 0);
+        assert_eq!(projected.batches[2].num_rows(), 2);
+        assert_eq!(table.project(&[0]).unwrap().batches, table.batches);
+        assert!(table.project(&[1]).is_err());
+    }
+
+    #[test]
+    fn slice_rows() {
+        let table = table();
+        assert_eq!(table.num_rows(), 6);
 
-        Ok(Self {
-            field: schema,
-            arrays: ffi_batches,
-        })
+        // Rows 1 to 4 span all three batches
+        let sliced = table.slice(1, 4);
+        assert_eq!(sliced.num_rows(), 4);
+        let lengths: Vec<_> = sliced.batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(lengths, [1, 2, 1]);
+        assert_eq!(sliced.batches[0], table.batches[0].slice(1, 1));
+
+        // Batches outside of the range are skipped entirely
+        assert_eq!(table.slice(2, 2).batches, table.batches[1..2]);
+        assert_eq!(table.slice(10, 2).num_batches(), 0);
+        assert_eq!(table.slice(5, 10).num_rows(), 1);
+
+        assert_eq!(table.head(3).batches, table.slice(0, 3).batches);
+        assert_eq!(table.tail(3).batches, table.slice(3, 3).batches);
+        assert_eq!(table.tail(10).num_rows(), 6);
+        assert_eq!(table.head(0).num_batches(), 0);
+    }
+
+    #[test]
+    fn rechunk_rows() {
+        let table = table();
+        let lengths =
+            |table: &Table| -> Vec<usize> { table.batches.iter().map(|b| b.num_rows()).collect() };
+        let rows = |table: &Table| -> RecordBatch {
+            concat_batches(&table.schema, &table.batches).unwrap()
+        };
+
+        let limits = ChunkLimits {
+            max_rows: Some(4),
+            max_bytes: None,
+        };
+        let rechunked = table.rechunk_batches(&limits).unwrap();
+        assert_eq!(lengths(&rechunked), [4, 2]);
+        assert_eq!(rows(&rechunked), rows(&table));
+
+        // Each Int32 row takes 4 bytes
+        let limits = ChunkLimits {
+            max_rows: None,
+            max_bytes: Some(12),
+        };
+        let rechunked = table.rechunk_batches(&limits).unwrap();
+        assert_eq!(lengths(&rechunked), [3, 3]);
+        assert_eq!(rows(&rechunked), rows(&table));
+
+        // A row larger than `max_bytes` still makes up a batch
+        let limits = ChunkLimits {
+            max_rows: None,
+            max_bytes: Some(1),
+        };
+        assert_eq!(lengths(&table.rechunk_batches(&limits).unwrap()), [1; 6]);
+
+        let combined = table.rechunk_batches(&ChunkLimits::default()).unwrap();
+        assert_eq!(lengths(&combined), [6]);
+        assert_eq!(combined.batches, table.combine_batches().unwrap().batches);
+
+        let limits = ChunkLimits {
+            max_rows: Some(0),
+            max_bytes: None,
+        };
+        assert!(table.rechunk_batches(&limits).is_err());
+    }
+
+    #[test]
+    fn combine_and_rechunk_empty_table() {
+        let (schema, batches) = three_batch_table();
+        let empty = vec![RecordBatch::new_empty(schema.clone())];
+        for table in [
+            Table::new(schema.clone(), vec![]),
+            Table::new(schema.clone(), vec![batches[0].slice(0, 0); 2]),
+        ] {
+            // Both yield a single empty batch, so the schema survives an IPC round trip
+            let combined = table.combine_batches().unwrap();
+            assert_eq!(combined.batches, empty);
+            let rechunked = table.rechunk_batches(&ChunkLimits::default()).unwrap();
+            assert_eq!(rechunked.batches, combined.batches);
+        }
     }
 }
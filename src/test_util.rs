@@ -0,0 +1,56 @@
+//! Fixtures shared by the unit tests of several modules.
+
+use std::sync::Arc;
+
+use arrow_array::{Int32Array, RecordBatch};
+use arrow_ipc::root_as_message;
+use arrow_ipc::writer::{
+    write_message, CompressionContext, DictionaryTracker, IpcDataGenerator, IpcWriteOptions,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+/// A schema with a single non-nullable Int32 column `a`, along with three batches of two rows
+/// each: `[0, 1]`, `[1, 2]` and `[2, 3]`.
+pub(crate) fn three_batch_table() -> (SchemaRef, Vec<RecordBatch>) {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+    let batches = (0..3)
+        .map(|i| {
+            let array = Int32Array::from(vec![i, i + 1]);
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        })
+        .collect();
+    (schema, batches)
+}
+
+/// Encode the first batch of [three_batch_table] as an encapsulated IPC message whose header
+/// claims a body of `body_length` bytes, returning the schema along with the message.
+pub(crate) fn batch_message_with_body_length(body_length: i64) -> (SchemaRef, Vec<u8>) {
+    let (schema, batches) = three_batch_table();
+    let options = IpcWriteOptions::default();
+    let (_, mut encoded) = IpcDataGenerator::default()
+        .encode(
+            &batches[0],
+            &mut DictionaryTracker::new(false),
+            &options,
+            &mut CompressionContext::default(),
+        )
+        .unwrap();
+
+    // The body length is the only field of the header with this value
+    let actual = (encoded.arrow_data.len() as i64).to_le_bytes();
+    let positions = encoded
+        .ipc_message
+        .windows(8)
+        .enumerate()
+        .filter(|(_, bytes)| *bytes == actual)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    assert_eq!(positions.len(), 1);
+    encoded.ipc_message[positions[0]..positions[0] + 8].copy_from_slice(&body_length.to_le_bytes());
+    let message = root_as_message(&encoded.ipc_message).unwrap();
+    assert_eq!(message.bodyLength(), body_length);
+
+    let mut output = vec![];
+    write_message(&mut output, encoded, &options).unwrap();
+    (schema, output)
+}
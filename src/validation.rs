@@ -0,0 +1,108 @@
+//! Validating Arrow data that was produced outside of this instance.
+
+use arrow_array::{Array, RecordBatch};
+use arrow_data::ArrayData;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::{Result, WasmResult};
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_Validation: &'static str = r#"
+export type Validation = "none" | "basic" | "full";
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Validation")]
+    pub type Validation;
+}
+
+/// How thoroughly to check imported Arrow data before using it.
+///
+/// - `None` performs no checks at all. It must only be used for data produced by a trusted
+///   writer, as malformed data can cause panics or undefined behavior when it is accessed.
+/// - `Basic` checks the layout of every array: buffer sizes, child lengths and the bounds of the
+///   first and last offsets. It takes constant time per array.
+/// - `Full` additionally checks every offset, every UTF-8 string, every dictionary key and the
+///   null counts. It takes time linear in the size of the data, and is the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationLevel {
+    None,
+    Basic,
+    #[default]
+    Full,
+}
+
+impl ValidationLevel {
+    /// Parse a validation level from a JS string, using [ValidationLevel::Full] if `validation`
+    /// is not provided.
+    pub fn from_js(validation: Option<Validation>) -> WasmResult<Self> {
+        match validation {
+            Some(validation) => Ok(serde_wasm_bindgen::from_value(validation.into())?),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Validate `data` and all of its children at this level.
+    pub fn validate(self, data: &ArrayData) -> Result<()> {
+        match self {
+            Self::None => {}
+            Self::Basic => validate_layout(data)?,
+            Self::Full => data.validate_full()?,
+        }
+        Ok(())
+    }
+
+    /// Validate every column of `batch` at this level.
+    pub fn validate_batch(self, batch: &RecordBatch) -> Result<()> {
+        if self == Self::None {
+            return Ok(());
+        }
+        batch
+            .columns()
+            .iter()
+            .try_for_each(|column| self.validate(&column.to_data()))
+    }
+}
+
+fn validate_layout(data: &ArrayData) -> std::result::Result<(), arrow_schema::ArrowError> {
+    data.validate()?;
+    data.child_data().iter().try_for_each(validate_layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_buffer::Buffer;
+    use arrow_schema::DataType;
+
+    use super::*;
+
+    #[test]
+    fn validation_levels() {
+        // Offsets within bounds, but the values are not valid UTF-8
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(1)
+                .add_buffer(Buffer::from_slice_ref([0i32, 2]))
+                .add_buffer(Buffer::from_slice_ref([0xffu8, 0xfe]))
+                .build_unchecked()
+        };
+        assert!(ValidationLevel::None.validate(&data).is_ok());
+        assert!(ValidationLevel::Basic.validate(&data).is_ok());
+        assert!(ValidationLevel::Full.validate(&data).is_err());
+
+        // The offsets buffer is too short for the length
+        let data = unsafe {
+            ArrayData::builder(DataType::Utf8)
+                .len(3)
+                .add_buffer(Buffer::from_slice_ref([0i32, 1]))
+                .add_buffer(Buffer::from_slice_ref(b"a"))
+                .build_unchecked()
+        };
+        assert!(ValidationLevel::None.validate(&data).is_ok());
+        assert!(ValidationLevel::Basic.validate(&data).is_err());
+        assert!(ValidationLevel::Full.validate(&data).is_err());
+    }
+}
@@ -1,12 +1,16 @@
 use std::sync::Arc;
 
-use arrow_array::ArrayRef;
+use arrow_array::{Array, ArrayRef};
 use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use wasm_bindgen::prelude::*;
 
-use crate::error::Result;
+use crate::error::{Result, WasmResult};
+use crate::ffi::FFIStream;
 use crate::ArrowWasmError;
 
+/// A chunked column of Arrow data in WebAssembly memory, with an associated field.
+///
+/// This has the same underlying representation as an Arrow JS `Vector` object.
 #[wasm_bindgen]
 pub struct Vector {
     chunks: Vec<ArrayRef>,
@@ -52,6 +56,68 @@ impl Vector {
     }
 }
 
+#[wasm_bindgen]
+impl Vector {
+    /// The number of chunks in this Vector.
+    #[wasm_bindgen(getter, js_name = numChunks)]
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// The total number of elements across all chunks of this Vector.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    /// The {@linkcode Field} describing this Vector.
+    #[cfg(feature = "field")]
+    #[wasm_bindgen(getter)]
+    pub fn field(&self) -> crate::Field {
+        self.field.clone().into()
+    }
+
+    /// Export this Vector to FFI structs according to the Arrow C Data Interface.
+    ///
+    /// The schema of the returned {@linkcode FFIStream} is this Vector's field, including any
+    /// extension type metadata, and each array is one chunk of the Vector.
+    ///
+    /// This method **does not consume** the Vector, so you must remember to call {@linkcode
+    /// Vector.free} to release the resources. The underlying arrays are reference counted, so
+    /// this method does not copy data, it only prevents the data from being released.
+    #[wasm_bindgen(js_name = toFFI)]
+    pub fn to_ffi(&self) -> WasmResult<FFIStream> {
+        Ok(self.try_into()?)
+    }
+
+    /// Export this Vector to FFI structs according to the Arrow C Data Interface.
+    ///
+    /// This method **does consume** the Vector, so the original Vector will be
+    /// inaccessible after this call. You must still call {@linkcode FFIStream.free} after
+    /// you've finished using the FFIStream.
+    #[wasm_bindgen(js_name = intoFFI)]
+    pub fn into_ffi(self) -> WasmResult<FFIStream> {
+        Ok((&self).try_into()?)
+    }
+
+    /// Returns the total number of bytes of memory occupied physically by all chunks in this
+    /// Vector.
+    #[wasm_bindgen(js_name = getArrayMemorySize)]
+    pub fn get_array_memory_size(&self) -> usize {
+        self.chunks
+            .iter()
+            .fold(0, |sum, chunk| sum + chunk.get_array_memory_size())
+    }
+}
+
+impl TryFrom<&Vector> for FFIStream {
+    type Error = ArrowWasmError;
+
+    fn try_from(value: &Vector) -> Result<Self> {
+        FFIStream::from_arrow(value.field.as_ref(), &value.chunks)
+    }
+}
+
 impl TryFrom<Vec<ArrayRef>> for Vector {
     type Error = ArrowWasmError;
 